@@ -21,14 +21,54 @@
 // SOFTWARE.
 
 use crate::{DerefHolder, TargetHolder};
+/// 格式化特型
+///
+/// 标识一次格式化是经由哪个格式化特型（`{}`、`{:x}`、`{:?}` 等）发起的，
+/// 见 [`FmtHandler::fmt_trait`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FmtTrait {
+    Debug,
+    Display,
+    Binary,
+    LowerExp,
+    UpperExp,
+    LowerHex,
+    UpperHex,
+    Octal,
+    Pointer,
+}
+
 pub trait FmtHandler<Inner: ?::core::marker::Sized>: ::core::marker::Copy {
     fn fmt(&self, inner: &Inner, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result;
+    /// 与 [`FmtHandler::fmt`] 相同，但额外获知发起格式化的特型。
+    ///
+    /// [`TargetHolder`] 与 [`DerefHolder`] 总是调用此方法。默认实现忽略 `fmt_trait`,
+    /// 因而格式化结果只与格式有关，而与对应的格式化特型无关；需要区分 `{:x}` 与 `{:X}` 等的处理器可覆盖之。
+    #[inline(always)]
+    fn fmt_trait(
+        &self,
+        inner: &Inner,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        let _ = fmt_trait;
+        self.fmt(inner, f)
+    }
 }
 impl<Inner: ?::core::marker::Sized, T: FmtHandler<Inner>> FmtHandler<Inner> for &T {
     #[inline]
     fn fmt(&self, inner: &Inner, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         (*self).fmt(inner, f)
     }
+    #[inline]
+    fn fmt_trait(
+        &self,
+        inner: &Inner,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        (*self).fmt_trait(inner, f, fmt_trait)
+    }
 }
 pub trait FmtBy {
     #[inline(always)]
@@ -163,4 +203,37 @@ mod tests {
         let proxy = s.fmt_by(CustomExtraProxy('*', 3));
         assert_eq!(format!("{}", proxy), "*test***");
     }
+
+    #[test]
+    fn test_fmt_trait() {
+        // 定义一个区分格式化特型的代理
+        #[derive(Debug, Clone, Copy, Default)]
+        struct TraitNameProxy;
+
+        impl FmtHandler<i32> for TraitNameProxy {
+            fn fmt(&self, data: &i32, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{}", data)
+            }
+            fn fmt_trait(
+                &self,
+                data: &i32,
+                f: &mut ::core::fmt::Formatter<'_>,
+                fmt_trait: FmtTrait,
+            ) -> ::core::fmt::Result {
+                write!(f, "{:?}({})", fmt_trait, data)
+            }
+        }
+
+        let n = 1;
+        assert_eq!(format!("{}", n.fmt_by(TraitNameProxy)), "Display(1)");
+        assert_eq!(format!("{:X}", n.fmt_by(&TraitNameProxy)), "UpperHex(1)");
+        // 直接调用 `fmt` 时不携带格式化特型
+        assert_eq!(
+            format!(
+                "{:?}",
+                crate::FmtFn(|f: &mut ::core::fmt::Formatter| TraitNameProxy.fmt(&n, f))
+            ),
+            "1"
+        );
+    }
 }
@@ -150,7 +150,7 @@ impl<T: ::core::ops::Deref, D> ::core::convert::AsRef<T> for DerefHolder<T, D> {
 }
 
 macro_rules! fmt_impl_for_fmt_by_holder {
-    ($trait_name: path) => {
+    ($trait_name: path, $fmt_trait: ident) => {
         #[doc = concat!("为 [`FmtByHolder`] 实现 [`", stringify!($trait_name), "`](::core::fmt::", stringify!($trait_name), ") 格式化特型，使之可作用于", stringify!($trait_name), "输出。")]
         impl<'a, T, D> $trait_name for TargetHolder<'a, T, D>
         where
@@ -159,14 +159,14 @@ macro_rules! fmt_impl_for_fmt_by_holder {
         {
             #[inline(always)]
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                self.handler.fmt(self.inner, f)
+                self.handler.fmt_trait(self.inner, f, crate::FmtTrait::$fmt_trait)
             }
         }
     };
 }
 
 macro_rules! fmt_impl_for_deref_holder {
-    ($trait_name: path) => {
+    ($trait_name: path, $fmt_trait: ident) => {
         #[doc = concat!("为 [`DerefHolder`] 实现 [`", stringify!($trait_name), "`](::core::fmt::", stringify!($trait_name), ") 格式化特型，使之可作用于", stringify!($trait_name), "输出。")]
         impl< T, D> $trait_name for DerefHolder<T, D>
         where
@@ -175,7 +175,7 @@ macro_rules! fmt_impl_for_deref_holder {
         {
             #[inline(always)]
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                self.handler.fmt(&self.inner, f)
+                self.handler.fmt_trait(&self.inner, f, crate::FmtTrait::$fmt_trait)
             }
         }
     };
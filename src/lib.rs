@@ -23,6 +23,9 @@
 #![cfg_attr(not(test), no_std)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod fmt_as;
 mod fmt_by;
 mod fmt_by_holder;
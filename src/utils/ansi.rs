@@ -0,0 +1,558 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{AnsiScanner, DisplayProxy, FmtHandler, FmtTrait, TextFilter, impl_filter_handler};
+
+static STYLING: ::core::sync::atomic::AtomicBool = ::core::sync::atomic::AtomicBool::new(true);
+
+/// 全局启用或禁用 [`Styled`] 的样式。禁用后 [`Styled`] 与其内部处理器完全相同。
+///
+/// 通常在程序启动时，按输出是否为终端或 `NO_COLOR` 环境变量设置一次。
+///
+/// # 示例
+/// ``` rust,standalone_crate
+/// use wfu::{Color, FmtBy, Styled, set_styling, styling};
+/// let red = Styled::new().with_fg(Color::Red);
+/// set_styling(false);
+/// assert_eq!(format!("{}", "x".fmt_by(red)), "x");
+/// set_styling(true);
+/// assert!(styling());
+/// ```
+#[inline]
+pub fn set_styling(enabled: bool) {
+    STYLING.store(enabled, ::core::sync::atomic::Ordering::Relaxed);
+}
+
+/// [`Styled`] 的样式是否全局启用，默认为 `true`.
+#[inline]
+pub fn styling() -> bool {
+    STYLING.load(::core::sync::atomic::Ordering::Relaxed)
+}
+
+/// 终端颜色，见 [`Styled`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// 256 色调色板中的颜色。
+    Ansi256(u8),
+    /// 24 位真彩色。
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// 以 SGR 参数的形式写入 `w`, `background` 为 `true` 时为背景色。
+    fn write_sgr(self, w: &mut impl ::core::fmt::Write, background: bool) -> ::core::fmt::Result {
+        let base = if background { 40 } else { 30 };
+        let extended = if background { 48 } else { 38 };
+        let basic = |n: u8| match n {
+            0..8 => base + n,
+            _ => base + 60 + n - 8,
+        };
+        match self {
+            Self::Ansi256(n) => write!(w, "{};5;{}", extended, n),
+            Self::Rgb(r, g, b) => write!(w, "{};2;{};{};{}", extended, r, g, b),
+            _ => {
+                let index = [
+                    Self::Black,
+                    Self::Red,
+                    Self::Green,
+                    Self::Yellow,
+                    Self::Blue,
+                    Self::Magenta,
+                    Self::Cyan,
+                    Self::White,
+                    Self::BrightBlack,
+                    Self::BrightRed,
+                    Self::BrightGreen,
+                    Self::BrightYellow,
+                    Self::BrightBlue,
+                    Self::BrightMagenta,
+                    Self::BrightCyan,
+                    Self::BrightWhite,
+                ]
+                .iter()
+                .position(|&color| color == self)
+                .unwrap_or_default();
+                write!(w, "{}", basic(index as u8))
+            }
+        }
+    }
+}
+
+/// 在栈上拼接转义序列，以便一次写出，不被其他过滤器拆开。
+struct EscapeBuf {
+    buf: [u8; 64],
+    len: usize,
+}
+
+impl EscapeBuf {
+    #[inline]
+    fn as_str(&self) -> &str {
+        // 只写入过完整的 `&str`
+        ::core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+impl ::core::fmt::Write for EscapeBuf {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(::core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// 用于以 ANSI SGR 转义序列为内部处理器的输出设置样式的格式化处理器
+///
+/// 在输出前写入样式，输出后复原。颜色支持 16 色、256 色与真彩色（见 [`Color`]）。
+/// `enabled` 为 `false` 或经 [`set_styling`] 全局禁用时，与内部处理器完全相同。
+///
+/// [`str_width`](crate::str_width) 测量宽度时忽略转义序列，[`Padded`](crate::Padded)、[`Table`](crate::Table)、
+/// [`Boxed`](crate::Boxed)、[`SideBySide`](crate::SideBySide)、[`Wrap`](crate::Wrap) 与
+/// [`ExpandTabs`](crate::ExpandTabs) 亦然，因此可以与之组合使用。
+///
+/// # 字段
+/// - `fg`、`bg`: 前景色与背景色。
+/// - `bold`、`italic`、`underline`、`dim`: 粗体、斜体、下划线与暗色。
+/// - `enabled`: 是否启用样式，默认为 `true`.
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{Color, FmtBy, Padded, Styled};
+/// let error = Styled::new().with_fg(Color::Red).with_bold(true);
+/// assert_eq!(format!("{}", "error".fmt_by(error)), "\x1b[1;31merror\x1b[0m");
+/// // 填充时不计入转义序列
+/// let padded = Padded(error.with_fg(Color::Rgb(255, 128, 0)));
+/// assert_eq!(format!("{:>7}", "warn".fmt_by(padded)), "   \x1b[1;38;2;255;128;0mwarn\x1b[0m");
+/// assert_eq!(format!("{}", "plain".fmt_by(error.with_enabled(false))), "plain");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Styled<Handler = DisplayProxy> {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub dim: bool,
+    pub enabled: bool,
+    pub handler: Handler,
+}
+
+impl Styled {
+    /// 以 [`DisplayProxy`] 为内部处理器构造，不设置任何样式。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            bold: false,
+            italic: false,
+            underline: false,
+            dim: false,
+            enabled: true,
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl ::core::default::Default for Styled {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Handler> Styled<Handler> {
+    /// 设置前景色。
+    #[inline]
+    pub fn with_fg(self, fg: Color) -> Self {
+        Self {
+            fg: Some(fg),
+            ..self
+        }
+    }
+    /// 设置背景色。
+    #[inline]
+    pub fn with_bg(self, bg: Color) -> Self {
+        Self {
+            bg: Some(bg),
+            ..self
+        }
+    }
+    /// 设置是否为粗体。
+    #[inline]
+    pub fn with_bold(self, bold: bool) -> Self {
+        Self { bold, ..self }
+    }
+    /// 设置是否为斜体。
+    #[inline]
+    pub fn with_italic(self, italic: bool) -> Self {
+        Self { italic, ..self }
+    }
+    /// 设置是否带下划线。
+    #[inline]
+    pub fn with_underline(self, underline: bool) -> Self {
+        Self { underline, ..self }
+    }
+    /// 设置是否为暗色。
+    #[inline]
+    pub fn with_dim(self, dim: bool) -> Self {
+        Self { dim, ..self }
+    }
+    /// 设置是否启用样式。
+    #[inline]
+    pub fn with_enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+    /// 替换内部处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> Styled<NewHandler> {
+        Styled {
+            fg: self.fg,
+            bg: self.bg,
+            bold: self.bold,
+            italic: self.italic,
+            underline: self.underline,
+            dim: self.dim,
+            enabled: self.enabled,
+            handler,
+        }
+    }
+    /// 拼接样式的转义序列，未设置样式时为空。
+    fn escape(&self) -> EscapeBuf {
+        use ::core::fmt::Write;
+        let mut buf = EscapeBuf {
+            buf: [0; 64],
+            len: 0,
+        };
+        let flags = [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+        ];
+        let mut params = flags.iter().filter(|(on, _)| *on).map(|&(_, sgr)| sgr);
+        // 最长的序列（四种样式与两个真彩色）不足 48 字节，不会超出缓冲区
+        let _ = buf.write_str("\x1b[");
+        let mut first = true;
+        let mut separator = |buf: &mut EscapeBuf| match ::core::mem::take(&mut first) {
+            true => Ok(()),
+            false => buf.write_str(";"),
+        };
+        let _ = params.try_for_each(|sgr| {
+            separator(&mut buf)?;
+            buf.write_str(sgr)
+        });
+        for (color, background) in [(self.fg, false), (self.bg, true)] {
+            if let Some(color) = color {
+                let _ = separator(&mut buf);
+                let _ = color.write_sgr(&mut buf, background);
+            }
+        }
+        if first {
+            buf.len = 0;
+        } else {
+            let _ = buf.write_str("m");
+        }
+        buf
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for Styled<Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        let escape = self.escape();
+        if !self.enabled || escape.len == 0 || !styling() {
+            return self.handler.fmt_trait(data, f, fmt_trait);
+        }
+        f.write_str(escape.as_str())?;
+        self.handler.fmt_trait(data, f, fmt_trait)?;
+        f.write_str("\x1b[0m")
+    }
+}
+
+/// 用于将内部处理器的输出包装为终端超链接（OSC 8 转义序列）的格式化处理器
+///
+/// 支持的终端会将输出显示为可点击的链接，不支持的终端通常只显示文本。`enabled` 为 `false` 时改为输出
+/// `文本 (url)`, 适用于不支持超链接的终端或写入文件的场景。
+///
+/// 与 [`Styled`] 相同，测量宽度时忽略转义序列，因此可以与 [`Padded`](crate::Padded)、[`Table`](crate::Table)
+/// 等组合使用。
+///
+/// # 字段
+/// - `url`: 链接地址。
+/// - `enabled`: 是否输出转义序列，默认为 `true`.
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, Hyperlink, Padded};
+/// let link = Hyperlink::new("https://example.com");
+/// assert_eq!(
+///     format!("{}", "site".fmt_by(link)),
+///     "\x1b]8;;https://example.com\x1b\\site\x1b]8;;\x1b\\"
+/// );
+/// assert_eq!(
+///     format!("{}", "site".fmt_by(link.with_enabled(false))),
+///     "site (https://example.com)"
+/// );
+/// // 填充时不计入转义序列
+/// let padded = format!("{:6}|", "site".fmt_by(Padded(link)));
+/// assert!(padded.ends_with("\x1b\\  |"));
+/// ```
+/// # 注意
+/// - `url` 原样输出，不应包含控制字符。
+/// - 不受 [`set_styling`] 影响。
+#[derive(Debug, Clone, Copy)]
+pub struct Hyperlink<'a, Handler = DisplayProxy> {
+    pub url: &'a str,
+    pub enabled: bool,
+    pub handler: Handler,
+}
+
+impl<'a> Hyperlink<'a> {
+    /// 以 [`DisplayProxy`] 为内部处理器构造链接到 `url` 的超链接。
+    #[inline]
+    pub const fn new(url: &'a str) -> Self {
+        Self {
+            url,
+            enabled: true,
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl<'a, Handler> Hyperlink<'a, Handler> {
+    /// 设置是否输出转义序列，为 `false` 时输出 `文本 (url)`.
+    #[inline]
+    pub fn with_enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+    /// 替换内部处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> Hyperlink<'a, NewHandler> {
+        Hyperlink {
+            url: self.url,
+            enabled: self.enabled,
+            handler,
+        }
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for Hyperlink<'_, Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        if !self.enabled {
+            self.handler.fmt_trait(data, f, fmt_trait)?;
+            f.write_str(" (")?;
+            f.write_str(self.url)?;
+            return f.write_str(")");
+        }
+        f.write_str("\x1b]8;;")?;
+        f.write_str(self.url)?;
+        f.write_str("\x1b\\")?;
+        self.handler.fmt_trait(data, f, fmt_trait)?;
+        f.write_str("\x1b]8;;\x1b\\")
+    }
+}
+
+/// 删除 ANSI 转义序列。
+#[derive(Debug, Clone, Copy, Default)]
+struct StripAnsiFilter(AnsiScanner);
+
+impl TextFilter for StripAnsiFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        let mut start = None;
+        for (i, c) in s.char_indices() {
+            match (self.0.in_escape(c), start) {
+                (true, Some(from)) => {
+                    out.write_str(&s[from..i])?;
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => {}
+            }
+        }
+        match start {
+            Some(from) => out.write_str(&s[from..]),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 用于删除内部处理器输出中的 ANSI 转义序列（颜色、样式、超链接等）的格式化处理器
+///
+/// 转义序列可以跨越内部处理器的多次写入。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{Color, FmtAs, FmtBy, StripAnsi, Styled};
+/// let styled = Styled::new().with_fg(Color::Green);
+/// assert_eq!(format!("{}", "ok".fmt_by(StripAnsi(styled))), "ok");
+/// assert_eq!(format!("{}", "\x1b[4mlink\x1b[24m".fmt_as::<StripAnsi>()), "link");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StripAnsi<Handler = DisplayProxy>(pub Handler);
+
+impl_filter_handler!(StripAnsi, |this| StripAnsiFilter::default(), this.0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Boxed, FmtAs, FmtBy, Table, TableStyle};
+
+    #[test]
+    fn test_styled() {
+        // 测试各种颜色与样式
+        let styled = Styled::new()
+            .with_fg(Color::BrightCyan)
+            .with_bg(Color::Ansi256(236))
+            .with_italic(true)
+            .with_underline(true)
+            .with_dim(true);
+        assert_eq!(
+            format!("{}", 'x'.fmt_by(styled)),
+            "\x1b[2;3;4;96;48;5;236mx\x1b[0m"
+        );
+        let styled = Styled::new().with_bg(Color::White).with_fg(Color::Black);
+        assert_eq!(format!("{:>3}", 1.fmt_by(styled)), "\x1b[30;47m  1\x1b[0m");
+        assert_eq!(format!("{}", 1.fmt_by(Styled::new())), "1");
+        let styled = Styled {
+            fg: Some(Color::Red),
+            ..Default::default()
+        };
+        assert_eq!(format!("{}", 1.fmt_by(styled)), "\x1b[31m1\x1b[0m");
+
+        // 测试测量宽度时忽略转义序列
+        let red = Styled::new().with_fg(Color::Red);
+        let boxed = Boxed::new().with_handler(red);
+        assert_eq!(
+            format!("{}", "ab".fmt_by(boxed)),
+            "┌────┐\n│ \x1b[31mab\x1b[0m │\n└────┘"
+        );
+        let rows = [[1, 22], [333, 4]];
+        let table = Table::new().with_style(TableStyle::Unicode);
+        let styled_rows = rows.map(|row| row.map(|n| format!("{}", n.fmt_by(red))));
+        assert_eq!(
+            format!("{}", styled_rows.fmt_by(StripAnsi(table))),
+            format!("{}", rows.fmt_by(table))
+        );
+    }
+
+    #[test]
+    fn test_hyperlink() {
+        // 测试转义序列与回退输出
+        let link = Hyperlink::new("https://a.b/c");
+        assert_eq!(
+            format!("{}", 255.fmt_by(link)),
+            "\x1b]8;;https://a.b/c\x1b\\255\x1b]8;;\x1b\\"
+        );
+        let fallback = link.with_enabled(false).with_handler(crate::DebugProxy);
+        assert_eq!(format!("{}", "x".fmt_by(fallback)), "\"x\" (https://a.b/c)");
+        assert_eq!(format!("{}", "site".fmt_by(StripAnsi(link))), "site");
+
+        // 测试测量宽度时忽略跨越多次写入的转义序列
+        let padded = crate::Padded(link);
+        assert_eq!(
+            format!(
+                "{}",
+                format!("{:^6}", "ab".fmt_by(padded)).fmt_as::<StripAnsi>()
+            ),
+            "  ab  "
+        );
+        let boxed = Boxed::new().with_handler(link);
+        assert_eq!(
+            format!("{}", "ab".fmt_by(StripAnsi(boxed))),
+            "┌────┐\n│ ab │\n└────┘"
+        );
+        let rows = [["a", "bb"], ["ccc", "d"]];
+        let links = rows.map(|row| row.map(|cell| cell.fmt_by(link)));
+        let table = Table::new().with_align(&[::core::fmt::Alignment::Right]);
+        assert_eq!(
+            format!("{}", links.fmt_by(StripAnsi(table))),
+            format!("{}", rows.fmt_by(table))
+        );
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        // 测试跨越多次写入的转义序列
+        let chunks = crate::FmtFn(|f: &mut ::core::fmt::Formatter<'_>| {
+            [
+                "a\x1b[3",
+                "8;5;1",
+                "mb\x1b]8;;u\x1b",
+                "\\c\x1b]8;;\x07",
+                "d",
+            ]
+            .iter()
+            .try_for_each(|chunk| f.write_str(chunk))
+        });
+        assert_eq!(
+            format!("{}", chunks.fmt_by(StripAnsi(DisplayProxy))),
+            "abcd"
+        );
+    }
+}
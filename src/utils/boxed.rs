@@ -0,0 +1,330 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    AnsiScanner, DisplayProxy, FmtHandler, FmtTrait, LineStats, TextFilter, fmt_filtered, str_width,
+};
+
+/// 边框样式，见 [`Boxed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BoxStyle {
+    /// `+`、`-` 与 `|`.
+    Ascii,
+    /// `┌─┐`.
+    #[default]
+    Single,
+    /// `╔═╗`.
+    Double,
+    /// `╭─╮`.
+    Rounded,
+    /// `┏━┓`.
+    Heavy,
+}
+
+impl BoxStyle {
+    /// 依次为：左上、右上、左下、右下、横线、竖线。
+    const fn chars(self) -> [char; 6] {
+        match self {
+            Self::Ascii => ['+', '+', '+', '+', '-', '|'],
+            Self::Single => ['┌', '┐', '└', '┘', '─', '│'],
+            Self::Double => ['╔', '╗', '╚', '╝', '═', '║'],
+            Self::Rounded => ['╭', '╮', '╰', '╯', '─', '│'],
+            Self::Heavy => ['┏', '┓', '┗', '┛', '━', '┃'],
+        }
+    }
+}
+
+/// 在每行两侧绘制竖线，并将各行补足至相同宽度。
+struct BoxFilter {
+    vertical: char,
+    padding: usize,
+    /// 竖线之间的宽度。
+    inner: usize,
+    /// 当前行已输出的宽度，行尚未开始时为 [`None`].
+    column: Option<usize>,
+    /// 是否已输出过行。
+    any_line: bool,
+    escapes: AnsiScanner,
+}
+
+impl BoxFilter {
+    fn open_line(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        if self.column.is_none() {
+            out.write_char('\n')?;
+            out.write_char(self.vertical)?;
+            write_repeated(out, ' ', self.padding)?;
+            self.column = Some(self.padding);
+        }
+        Ok(())
+    }
+    fn close_line(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        self.open_line(out)?;
+        let column = self.column.take().unwrap_or_default();
+        write_repeated(out, ' ', self.inner.saturating_sub(column))?;
+        out.write_char(self.vertical)?;
+        self.any_line = true;
+        Ok(())
+    }
+}
+
+impl TextFilter for BoxFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        for (i, line) in s.split('\n').enumerate() {
+            if i != 0 {
+                self.close_line(out)?;
+            }
+            if !line.is_empty() {
+                self.open_line(out)?;
+                out.write_str(line)?;
+                self.column = self
+                    .column
+                    .map(|column| column + self.escapes.str_width(line));
+            }
+        }
+        Ok(())
+    }
+    fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        // 末尾的换行符不产生空行，但空输出仍占一行
+        if self.column.is_some() || !self.any_line {
+            self.close_line(out)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_repeated(out: &mut ::core::fmt::Formatter<'_>, c: char, n: usize) -> ::core::fmt::Result {
+    use ::core::fmt::Write;
+    (0..n).try_for_each(|_| out.write_char(c))
+}
+
+/// 在内部处理器的输出周围绘制边框的格式化处理器
+///
+/// 先测量最宽一行的显示宽度（按 [`str_width`](crate::str_width) 计算，忽略 ANSI 转义序列），再逐行输出，
+/// 将各行以空格补足至相同宽度，并在两侧绘制竖线。标题嵌入顶边，边框会在必要时加宽以容纳标题。
+///
+/// # 字段
+/// - `style`: 边框样式，默认为 [`BoxStyle::Single`].
+/// - `padding`: 内容两侧的空格数，默认为 `1`.
+/// - `title`: 标题，为空时不显示。
+/// - `title_align`: 标题在顶边中的对齐方式，默认左对齐。
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{BoxStyle, Boxed, FmtBy};
+/// let text = "hello\n世界";
+/// assert_eq!(
+///     format!("{}", text.fmt_by(Boxed::new().with_title("Note"))),
+///     "\
+/// ┌─ Note ─┐
+/// │ hello  │
+/// │ 世界   │
+/// └────────┘"
+/// );
+/// assert_eq!(
+///     format!("{}", "hi".fmt_by(Boxed::new().with_style(BoxStyle::Ascii))),
+///     "+----+\n| hi |\n+----+"
+/// );
+/// ```
+/// # 注意
+/// - 内部处理器会被调用两次：一次测量宽度，一次输出，不分配内存。
+/// - 输出末尾的一个换行符不产生空行。
+/// - 制表符按零宽处理；需按列展开时，可先使用 [`ExpandTabs`](crate::ExpandTabs).
+#[derive(Debug, Clone, Copy)]
+pub struct Boxed<'a, Handler = DisplayProxy> {
+    pub style: BoxStyle,
+    pub padding: usize,
+    pub title: &'a str,
+    pub title_align: ::core::fmt::Alignment,
+    pub handler: Handler,
+}
+
+impl Boxed<'_> {
+    /// 单线边框、两侧各留一个空格、无标题，以 [`DisplayProxy`] 为内部处理器构造。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            style: BoxStyle::Single,
+            padding: 1,
+            title: "",
+            title_align: ::core::fmt::Alignment::Left,
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl ::core::default::Default for Boxed<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Handler> Boxed<'a, Handler> {
+    /// 替换边框样式。
+    #[inline]
+    pub fn with_style(self, style: BoxStyle) -> Self {
+        Self { style, ..self }
+    }
+    /// 替换内容两侧的空格数。
+    #[inline]
+    pub fn with_padding(self, padding: usize) -> Self {
+        Self { padding, ..self }
+    }
+    /// 设置左对齐的标题。
+    #[inline]
+    pub fn with_title(self, title: &'a str) -> Self {
+        Self { title, ..self }
+    }
+    /// 设置标题的对齐方式。
+    #[inline]
+    pub fn with_title_align(self, title_align: ::core::fmt::Alignment) -> Self {
+        Self {
+            title_align,
+            ..self
+        }
+    }
+    /// 替换内部处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> Boxed<'a, NewHandler> {
+        Boxed {
+            style: self.style,
+            padding: self.padding,
+            title: self.title,
+            title_align: self.title_align,
+            handler,
+        }
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for Boxed<'_, Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        use ::core::fmt::{Alignment, Write};
+        let mut measure = LineStats::default();
+        fmt_filtered(&mut measure, data, self.handler, f, fmt_trait)?;
+        let [
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            horizontal,
+            vertical,
+        ] = self.style.chars();
+        let title_width = str_width(self.title);
+        let mut inner = measure.widest + 2 * self.padding;
+        // 顶边
+        f.write_char(top_left)?;
+        if title_width == 0 {
+            write_repeated(f, horizontal, inner)?;
+        } else {
+            // 标题两侧各留一个空格，且至少与角相隔一条横线
+            inner = inner.max(title_width + 4);
+            let rest = inner - title_width - 2;
+            let before = match self.title_align {
+                Alignment::Left => 1,
+                Alignment::Right => rest - 1,
+                Alignment::Center => rest / 2,
+            };
+            write_repeated(f, horizontal, before)?;
+            write!(f, " {} ", self.title)?;
+            write_repeated(f, horizontal, rest - before)?;
+        }
+        f.write_char(top_right)?;
+        // 内容
+        let filter = BoxFilter {
+            vertical,
+            padding: self.padding,
+            inner,
+            column: None,
+            any_line: false,
+            escapes: AnsiScanner::default(),
+        };
+        fmt_filtered(filter, data, self.handler, f, fmt_trait)?;
+        // 底边
+        f.write_char('\n')?;
+        f.write_char(bottom_left)?;
+        write_repeated(f, horizontal, inner)?;
+        f.write_char(bottom_right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy, Joined};
+    use ::core::fmt::Alignment;
+
+    #[test]
+    fn test_boxed() {
+        // 测试空输出与末尾换行
+        assert_eq!(format!("{}", "".fmt_as::<Boxed>()), "┌──┐\n│  │\n└──┘");
+        assert_eq!(
+            format!("{}", "a\n\nb\n".fmt_as::<Boxed>()),
+            "┌───┐\n│ a │\n│   │\n│ b │\n└───┘"
+        );
+
+        // 测试标题对齐与边框样式
+        let boxed = Boxed::new()
+            .with_style(BoxStyle::Double)
+            .with_padding(0)
+            .with_title("标题");
+        assert_eq!(
+            format!(
+                "{}",
+                "0123456789".fmt_by(boxed.with_title_align(Alignment::Right))
+            ),
+            "╔═══ 标题 ═╗\n║0123456789║\n╚══════════╝"
+        );
+        assert_eq!(
+            format!("{}", "x".fmt_by(boxed.with_title_align(Alignment::Center))),
+            "╔═ 标题 ═╗\n║x       ║\n╚════════╝"
+        );
+        let heavy = Boxed::new().with_style(BoxStyle::Heavy);
+        assert_eq!(format!("{}", 7.fmt_by(heavy)), "┏━━━┓\n┃ 7 ┃\n┗━━━┛");
+
+        // 测试嵌套与组合
+        let rows = ["ab", "c"];
+        let inner = Boxed::new()
+            .with_style(BoxStyle::Rounded)
+            .with_handler(Joined("\n"));
+        let outer = Boxed::new().with_style(BoxStyle::Ascii).with_handler(inner);
+        assert_eq!(
+            format!("{}", rows.fmt_by(outer)),
+            "+--------+\n| ╭────╮ |\n| │ ab │ |\n| │ c  │ |\n| ╰────╯ |\n+--------+"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{FmtHandler, FmtTrait};
+
+/// 将字节数据输出为紧凑的十六进制、二进制或八进制串的格式化处理器
+///
+/// 输出的进制取决于发起格式化的特型：
+/// - `{:x}` 为小写十六进制，`{:X}` 为大写十六进制；
+/// - `{:b}` 为二进制，每字节 8 位；
+/// - `{:o}` 为八进制，每字节 3 位；
+/// - 其余特型（如 `{}`、`{:?}`）与 `{:x}` 相同。
+///
+/// 使用 `#` 标志（如 `{:#x}`）时，在最前方添加 `0x`、`0b` 或 `0o` 前缀。
+///
+/// # 字段
+/// - `group`: 每组的字节数，组间以 `sep` 分隔；为 `0` 时不分组。
+/// - `sep`: 组分隔符，必须实现 [`Display`](::core::fmt::Display) 和 [`Copy`](::core::marker::Copy) ( 可以是引用 ) 特型。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{Bytes, FmtAs, FmtBy};
+/// let data = [0xde, 0xad, 0xbe, 0xef];
+/// assert_eq!(format!("{:x}", data.fmt_as::<Bytes>()), "deadbeef");
+/// assert_eq!(format!("{:#X}", data.fmt_as::<Bytes>()), "0xDEADBEEF");
+/// assert_eq!(format!("{:x}", data.fmt_by(Bytes::grouped(1, ':'))), "de:ad:be:ef");
+/// assert_eq!(format!("{:#x}", data.fmt_by(Bytes::grouped(2, '_'))), "0xdead_beef");
+/// assert_eq!(format!("{:b}", data[..1].fmt_as::<Bytes>()), "11011110");
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef), 如 `[u8]`、`[u8; N]`、`&[u8]`、`Vec<u8>` 等；
+/// - 仅当经由 [`TargetHolder`](crate::TargetHolder) 或 [`DerefHolder`](crate::DerefHolder) 格式化时才能获知格式化特型，
+///   直接调用 [`FmtHandler::fmt`] 时按 `{:x}` 输出。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bytes<Sep = &'static str> {
+    pub group: usize,
+    pub sep: Sep,
+}
+
+impl Bytes {
+    /// 不分组。
+    #[inline]
+    pub const fn new() -> Self {
+        Self { group: 0, sep: "" }
+    }
+}
+
+impl<Sep> Bytes<Sep> {
+    /// 每 `group` 个字节一组，组间以 `sep` 分隔。
+    #[inline]
+    pub const fn grouped(group: usize, sep: Sep) -> Self {
+        Self { group, sep }
+    }
+}
+
+impl ::core::default::Default for Bytes {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Sep> Bytes<Sep>
+where
+    Sep: ::core::fmt::Display,
+{
+    fn fmt_bytes(
+        &self,
+        data: &[u8],
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        if f.alternate() {
+            f.write_str(match fmt_trait {
+                FmtTrait::Binary => "0b",
+                FmtTrait::Octal => "0o",
+                _ => "0x",
+            })?;
+        }
+        for (i, byte) in data.iter().enumerate() {
+            if self.group != 0 && i != 0 && i % self.group == 0 {
+                self.sep.fmt(f)?;
+            }
+            match fmt_trait {
+                FmtTrait::Binary => write!(f, "{:08b}", byte)?,
+                FmtTrait::Octal => write!(f, "{:03o}", byte)?,
+                FmtTrait::UpperHex => write!(f, "{:02X}", byte)?,
+                _ => write!(f, "{:02x}", byte)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, Sep> FmtHandler<T> for Bytes<Sep>
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+    Sep: ::core::fmt::Display + ::core::marker::Copy,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_bytes(data.as_ref(), f, FmtTrait::LowerHex)
+    }
+    #[inline]
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        self.fmt_bytes(data.as_ref(), f, fmt_trait)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy};
+
+    #[test]
+    fn test_bytes() {
+        let data = vec![0x01u8, 0xab, 0xff];
+
+        // 测试各格式化特型
+        let proxy = data.fmt_as::<Bytes>();
+        assert_eq!(format!("{}", proxy), "01abff");
+        assert_eq!(format!("{:?}", proxy), "01abff");
+        assert_eq!(format!("{:X}", proxy), "01ABFF");
+        assert_eq!(format!("{:o}", proxy), "001253377");
+        assert_eq!(format!("{:#o}", proxy), "0o001253377");
+        assert_eq!(format!("{:#b}", proxy), "0b000000011010101111111111");
+
+        // 测试分组
+        let proxy = data.fmt_by(Bytes::grouped(2, " "));
+        assert_eq!(format!("{:#X}", proxy), "0x01AB FF");
+
+        // 测试切片与空数据
+        let slice: &[u8] = &data[1..];
+        assert_eq!(format!("{:x}", slice.fmt_as::<Bytes>()), "abff");
+        assert_eq!(format!("{:#x}", b"".fmt_as::<Bytes>()), "0x");
+    }
+}
@@ -0,0 +1,245 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DebugLayout, DebugProxy, FmtHandler, FmtTrait, ReDebug};
+
+/// [`ColorDebug`] 所用的颜色，各字段为 ANSI SGR 参数（如 `"1;36"`），为空时不着色。
+///
+/// # 字段
+/// - `name`: 结构体、元组结构体与枚举变体的名称。
+/// - `field`: 字段名与映射中作为键的标识符。
+/// - `string`: 字符串与字符字面量。
+/// - `number`: 数字。
+/// - `punct`: 括号、逗号与冒号。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DebugColors<'a> {
+    pub name: &'a str,
+    pub field: &'a str,
+    pub string: &'a str,
+    pub number: &'a str,
+    pub punct: &'a str,
+}
+
+impl DebugColors<'_> {
+    /// 默认配色：名称为粗体青色，字段名为蓝色，字符串为绿色，数字为黄色，标点为暗色。
+    pub const DEFAULT: Self = Self {
+        name: "1;36",
+        field: "34",
+        string: "32",
+        number: "33",
+        punct: "2",
+    };
+}
+
+impl ::core::default::Default for DebugColors<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// 用于为 [`Debug`](::core::fmt::Debug) 输出着色的格式化处理器
+///
+/// 以 [`DebugParser`](crate::DebugParser) 解析内部处理器的输出，按 [`DebugColors`] 以 ANSI 转义序列为各类记号着色。
+/// `{:?}` 输出在一行，`{:#?}` 每个元素一行，排版与派生的 [`Debug`](::core::fmt::Debug) 相同。
+///
+/// `enabled` 为 `false` 时直接调用内部处理器，与 [`DebugProxy`] 完全相同，
+/// 以便在输出不是终端时关闭颜色。
+///
+/// # 字段
+/// - `enabled`: 是否着色，默认为 `true`.
+/// - `colors`: 配色，默认为 [`DebugColors::DEFAULT`].
+/// - `handler`: 内部处理器，默认为 [`DebugProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{ColorDebug, DebugColors, FmtBy};
+/// #[derive(Debug)]
+/// struct Point {
+///     x: i32,
+/// }
+/// let colors = DebugColors { name: "1", field: "", string: "", number: "33", punct: "" };
+/// let color_debug = ColorDebug::new().with_colors(colors);
+/// assert_eq!(
+///     format!("{:?}", Point { x: 1 }.fmt_by(color_debug)),
+///     "\x1b[1mPoint\x1b[0m { x: \x1b[33m1\x1b[0m }"
+/// );
+/// let plain = color_debug.with_enabled(false);
+/// assert_eq!(format!("{:#?}", Point { x: 1 }.fmt_by(plain)), format!("{:#?}", Point { x: 1 }));
+/// ```
+/// # 注意
+/// - 自定义 [`Debug`](::core::fmt::Debug) 实现中多余的空白会被规整；需要原样输出时请关闭颜色。
+/// - 内部处理器总以 `{:?}` 的选项调用，格式化参数中的宽度被忽略。
+#[derive(Debug, Clone, Copy)]
+pub struct ColorDebug<'a, Handler = DebugProxy> {
+    pub enabled: bool,
+    pub colors: DebugColors<'a>,
+    pub handler: Handler,
+}
+
+impl ColorDebug<'_> {
+    /// 以默认配色与 [`DebugProxy`] 构造。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            enabled: true,
+            colors: DebugColors::DEFAULT,
+            handler: DebugProxy,
+        }
+    }
+}
+
+impl ::core::default::Default for ColorDebug<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Handler> ColorDebug<'a, Handler> {
+    /// 设置是否着色。
+    #[inline]
+    pub fn with_enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+    /// 替换配色。
+    #[inline]
+    pub fn with_colors(self, colors: DebugColors<'a>) -> Self {
+        Self { colors, ..self }
+    }
+    /// 替换内部处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> ColorDebug<'a, NewHandler> {
+        ColorDebug {
+            enabled: self.enabled,
+            colors: self.colors,
+            handler,
+        }
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for ColorDebug<'_, Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Debug)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        if !self.enabled {
+            return self.handler.fmt_trait(data, f, fmt_trait);
+        }
+        let layout = match f.alternate() {
+            true => DebugLayout::Expanded,
+            false => DebugLayout::Compact,
+        };
+        let re_debug = ReDebug::new().with_handler(self.handler);
+        re_debug.render(data, f, fmt_trait, layout, Some(self.colors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy};
+    use ::std::collections::BTreeMap;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    enum Shape {
+        Circle { radius: f32, label: &'static str },
+        Empty,
+    }
+
+    /// 去除 ANSI 转义序列
+    fn strip(s: &str) -> String {
+        let mut out = String::new();
+        let mut escape = false;
+        for c in s.chars() {
+            match c {
+                '\x1b' => escape = true,
+                'm' if escape => escape = false,
+                _ if escape => {}
+                _ => out.push(c),
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_color_debug() {
+        let shapes = [
+            Shape::Circle {
+                radius: -1.5,
+                label: "a \"b\"",
+            },
+            Shape::Empty,
+        ];
+        let colors = DebugColors {
+            name: "N",
+            field: "F",
+            string: "S",
+            number: "D",
+            punct: "P",
+        };
+        let color_debug = ColorDebug::new().with_colors(colors);
+        assert_eq!(
+            format!("{:?}", shapes.fmt_by(color_debug)),
+            "\x1b[Pm[\x1b[0m\x1b[NmCircle\x1b[0m \x1b[Pm{\x1b[0m \x1b[Fmradius\x1b[0m\x1b[Pm:\x1b[0m \
+             \x1b[Dm-1.5\x1b[0m\x1b[Pm,\x1b[0m \x1b[Fmlabel\x1b[0m\x1b[Pm:\x1b[0m \x1b[Sm\"a \\\"b\\\"\"\x1b[0m \
+             \x1b[Pm}\x1b[0m\x1b[Pm,\x1b[0m \x1b[NmEmpty\x1b[0m\x1b[Pm]\x1b[0m"
+        );
+        // 单元变体在逗号、闭括号之前或位于结尾时亦按名称着色，布尔值不着色
+        assert_eq!(
+            format!("{:?}", Shape::Empty.fmt_by(color_debug)),
+            "\x1b[NmEmpty\x1b[0m"
+        );
+        assert_eq!(
+            format!("{:?}", (None::<u8>, true).fmt_by(color_debug)),
+            "\x1b[Pm(\x1b[0m\x1b[NmNone\x1b[0m\x1b[Pm,\x1b[0m true\x1b[Pm)\x1b[0m"
+        );
+
+        // 去除颜色后与派生的输出相同
+        let mut map = BTreeMap::new();
+        map.insert("k", (1, Some(2u8)));
+        map.insert("v", (3, None));
+        assert_eq!(
+            strip(&format!("{:#?}", map.fmt_as::<ColorDebug>())),
+            format!("{:#?}", map)
+        );
+        assert_eq!(
+            strip(&format!("{:?}", shapes.fmt_as::<ColorDebug>())),
+            format!("{:?}", shapes)
+        );
+        assert_eq!(
+            format!("{:#?}", shapes.fmt_by(color_debug.with_enabled(false))),
+            format!("{:#?}", shapes)
+        );
+    }
+}
@@ -0,0 +1,282 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::FmtHandler;
+
+/// 遇到无效编码时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OnInvalid {
+    /// 以给定字符替换每段无效序列，默认为 U+FFFD `�`.
+    Replace(char),
+    /// 转义输出：无效字节输出为 `\xNN`, 孤立的代理项输出为 `\u{NNNN}`.
+    Escape,
+}
+
+impl ::core::default::Default for OnInvalid {
+    #[inline]
+    fn default() -> Self {
+        Self::Replace(::core::char::REPLACEMENT_CHARACTER)
+    }
+}
+
+impl OnInvalid {
+    fn fmt_bytes(self, bytes: &[u8], f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        match self {
+            Self::Replace(c) => f.write_char(c),
+            Self::Escape => bytes.iter().try_for_each(|b| write!(f, "\\x{:02x}", b)),
+        }
+    }
+    fn fmt_unit(self, unit: u16, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        match self {
+            Self::Replace(c) => f.write_char(c),
+            Self::Escape => write!(f, "\\u{{{:x}}}", unit),
+        }
+    }
+}
+
+/// 将字节数据按 UTF-8 有损解码输出的格式化处理器
+///
+/// 行为与 `String::from_utf8_lossy` 一致：每段最长的无效子序列替换为一个替换字符；
+/// 也可通过 [`OnInvalid::Escape`] 将无效字节转义输出。有效部分整段写入格式化器，不分配内存。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, FmtBy, OnInvalid, Utf8Lossy};
+/// let data = b"Hello \xF0\x90\x80World\xff";
+/// assert_eq!(format!("{}", data.fmt_as::<Utf8Lossy>()), "Hello �World�");
+/// assert_eq!(
+///     format!("{}", data.fmt_by(Utf8Lossy(OnInvalid::Escape))),
+///     r"Hello \xf0\x90\x80World\xff"
+/// );
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef);
+/// - 对于 [`CStr`](::core::ffi::CStr), 请使用 [`CStrLossy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Utf8Lossy(pub OnInvalid);
+
+impl Utf8Lossy {
+    fn fmt_bytes(&self, data: &[u8], f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        for chunk in data.utf8_chunks() {
+            f.write_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                self.0.fmt_bytes(chunk.invalid(), f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> FmtHandler<T> for Utf8Lossy
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_bytes(data.as_ref(), f)
+    }
+}
+
+/// 将 [`CStr`](::core::ffi::CStr) 按 UTF-8 有损解码输出的格式化处理器
+///
+/// 不含末尾的 `\0`, 其余同 [`Utf8Lossy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{CStrLossy, FmtAs};
+/// let s = c"caf\xe9";
+/// assert_eq!(format!("{}", s.fmt_as::<CStrLossy>()), "caf�");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CStrLossy(pub OnInvalid);
+
+impl<T> FmtHandler<T> for CStrLossy
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<::core::ffi::CStr>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        Utf8Lossy(self.0).fmt_bytes(data.as_ref().to_bytes(), f)
+    }
+}
+
+fn fmt_utf16(
+    units: impl ::core::iter::Iterator<Item = u16>,
+    on_invalid: OnInvalid,
+    f: &mut ::core::fmt::Formatter<'_>,
+) -> ::core::fmt::Result {
+    use ::core::fmt::Write;
+    for c in ::core::char::decode_utf16(units) {
+        match c {
+            Ok(c) => f.write_char(c)?,
+            Err(e) => on_invalid.fmt_unit(e.unpaired_surrogate(), f)?,
+        }
+    }
+    Ok(())
+}
+
+/// 将 UTF-16 码元按有损解码输出的格式化处理器
+///
+/// 孤立的代理项按 [`OnInvalid`] 处理。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, FmtBy, OnInvalid, Utf16};
+/// let units = [0x0048u16, 0x0069, 0xd83d, 0xde00, 0xdc00];
+/// assert_eq!(format!("{}", units.fmt_as::<Utf16>()), "Hi😀�");
+/// assert_eq!(format!("{}", units.fmt_by(Utf16(OnInvalid::Escape))), r"Hi😀\u{dc00}");
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u16]>`](::core::convert::AsRef);
+/// - 对于字节数据，请使用 [`Utf16Le`] 或 [`Utf16Be`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Utf16(pub OnInvalid);
+
+impl<T> FmtHandler<T> for Utf16
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u16]>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        fmt_utf16(data.as_ref().iter().copied(), self.0, f)
+    }
+}
+
+macro_rules! define_utf16_bytes_handler {
+    ($handler: ident, $from_bytes: ident, $endian: literal, $example: literal) => {
+        #[doc = concat!("将字节数据按 UTF-16", $endian, "有损解码输出的格式化处理器")]
+        ///
+        /// 孤立的代理项按 [`OnInvalid`] 处理；末尾多出的单个字节视作无效字节。
+        ///
+        /// # 示例
+        /// ``` rust
+        #[doc = concat!("use wfu::{FmtAs, ", stringify!($handler), "};")]
+        #[doc = concat!("let data = ", $example, ";")]
+        #[doc = concat!("assert_eq!(format!(\"{}\", data.fmt_as::<", stringify!($handler), ">()), \"Hi�\");")]
+        /// ```
+        /// # 注意
+        /// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef).
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $handler(pub OnInvalid);
+
+        impl<T> FmtHandler<T> for $handler
+        where
+            T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+        {
+            fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let chunks = data.as_ref().chunks_exact(2);
+                let remainder = chunks.remainder();
+                fmt_utf16(chunks.map(|c| u16::$from_bytes([c[0], c[1]])), self.0, f)?;
+                if !remainder.is_empty() {
+                    self.0.fmt_bytes(remainder, f)?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+define_utf16_bytes_handler!(Utf16Le, from_le_bytes, " 小端序", r#"b"H\0i\0\x3d""#);
+define_utf16_bytes_handler!(Utf16Be, from_be_bytes, " 大端序", r#"b"\0H\0i\xd8""#);
+
+/// 将字节数据按 Latin-1（ISO-8859-1）解码输出的格式化处理器
+///
+/// 每个字节直接映射为码位相同的 Unicode 字符，不存在无效输入。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, Latin1};
+/// assert_eq!(format!("{}", b"caf\xe9".fmt_as::<Latin1>()), "café");
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Latin1;
+
+impl<T> FmtHandler<T> for Latin1
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+{
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        for chunk in data.as_ref().split_inclusive(|b| !b.is_ascii()) {
+            // ASCII 部分整段写入。
+            let (ascii, last) = match chunk.split_last() {
+                Some((&last, ascii)) if !last.is_ascii() => (ascii, Some(last)),
+                _ => (chunk, None),
+            };
+            f.write_str(::core::str::from_utf8(ascii).map_err(|_| ::core::fmt::Error)?)?;
+            if let Some(last) = last {
+                f.write_char(last as char)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy};
+
+    #[test]
+    fn test_utf8_lossy() {
+        // 与 String::from_utf8_lossy 的结果一致
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"plain",
+            b"\xff\xfe",
+            b"a\xed\xa0\x80b",
+            b"\xe4\xbd\xa0\xe5\xa5\xbd\xe4\xbd",
+        ];
+        for input in inputs {
+            assert_eq!(
+                format!("{}", input.fmt_as::<Utf8Lossy>()),
+                String::from_utf8_lossy(input)
+            );
+        }
+
+        // 测试自定义替换字符
+        let proxy = b"a\x80b".fmt_by(Utf8Lossy(OnInvalid::Replace('?')));
+        assert_eq!(format!("{}", proxy), "a?b");
+    }
+
+    #[test]
+    fn test_utf16_and_latin1() {
+        let text = "héllo, 世界 🌍";
+        let units: Vec<u16> = text.encode_utf16().collect();
+        let le: Vec<u8> = units.iter().flat_map(|u| u.to_le_bytes()).collect();
+        let be: Vec<u8> = units.iter().flat_map(|u| u.to_be_bytes()).collect();
+        assert_eq!(format!("{}", units.fmt_as::<Utf16>()), text);
+        assert_eq!(format!("{}", le.fmt_as::<Utf16Le>()), text);
+        assert_eq!(format!("{}", be.fmt_as::<Utf16Be>()), text);
+
+        // 测试末尾多余字节的转义
+        let proxy = b"A\0\xd8".fmt_by(Utf16Le(OnInvalid::Escape));
+        assert_eq!(format!("{}", proxy), r"A\xd8");
+
+        let latin1: Vec<u8> = (0xe0..=0xe5).chain(*b" ok").collect();
+        assert_eq!(format!("{}", latin1.fmt_as::<Latin1>()), "àáâãäå ok");
+    }
+}
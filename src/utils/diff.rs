@@ -0,0 +1,504 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DebugProxy, FmtHandler, FmtOptions, FmtTrait};
+use ::alloc::{string::String, vec::Vec};
+
+/// 比较的粒度，见 [`Diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DiffMode {
+    /// 逐行比较，输出带上下文的统一差异格式。
+    #[default]
+    Line,
+    /// 逐词比较，将差异标注在完整的输出中。
+    Token,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Myers 差分算法，返回将 `a` 变为 `b` 的最短编辑序列。
+fn myers<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Op> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let offset = n + m;
+    let index = |k: isize| (k + offset) as usize;
+    let mut v = ::alloc::vec![0isize; 2 * offset as usize + 2];
+    // 依次存放第 d 步之前 `v` 中 `-d..=d` 的部分（起于 `d * d`, 共 `2d + 1` 项），用于回溯；
+    // 回溯时不会读取这一范围之外的项，因而所需空间为 O(D²) 而非 O((N + M)D).
+    let mut trace = Vec::new();
+    let mut steps = 0;
+    'search: for d in 0..=offset {
+        trace.extend_from_slice(&v[index(-d)..=index(d)]);
+        steps += 1;
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[index(k - 1)] < v[index(k + 1)]) {
+                v[index(k + 1)]
+            } else {
+                v[index(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[index(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+    let (mut x, mut y) = (n, m);
+    let mut ops = Vec::new();
+    for d in (0..steps).rev() {
+        let v = |k: isize| trace[(d * d + k + d) as usize];
+        let k = x - y;
+        // 第 0 步只有自起点出发的对角线
+        let (prev_x, prev_y) = if d == 0 {
+            (0, 0)
+        } else {
+            let prev_k = if k == -d || (k != d && v(k - 1) < v(k + 1)) {
+                k + 1
+            } else {
+                k - 1
+            };
+            (v(prev_k), v(prev_k) - prev_k)
+        };
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            ops.push(Op::Equal(x as usize, y as usize));
+        }
+        if d > 0 {
+            ops.push(match x == prev_x {
+                true => Op::Insert(prev_y as usize),
+                false => Op::Delete(prev_x as usize),
+            });
+        }
+        (x, y) = (prev_x, prev_y);
+    }
+    ops.reverse();
+    ops
+}
+
+/// 拆分为词：连续的字母数字、连续的空白，或单个其他字符。
+fn tokens(s: &str) -> Vec<&str> {
+    #[derive(PartialEq)]
+    enum Class {
+        Word,
+        Space,
+        Other,
+    }
+    let class = |c: char| match c {
+        _ if c.is_alphanumeric() || c == '_' => Class::Word,
+        _ if c.is_whitespace() => Class::Space,
+        _ => Class::Other,
+    };
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        let current = class(c);
+        let end = match chars.peek() {
+            Some(&(next, d)) if current == Class::Other || class(d) != current => next,
+            Some(_) => continue,
+            None => s.len(),
+        };
+        tokens.push(&s[start..end]);
+        start = end;
+    }
+    tokens
+}
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+const RESET: &str = "\x1b[0m";
+
+/// 用于显示两个值的格式化结果之间差异的包装器
+///
+/// 以同一个处理器格式化 `left` 与 `right`, 再比较二者的输出：
+/// - [`DiffMode::Line`]: 逐行比较，以统一差异格式输出发生变化的行及其前后 `context` 行，
+///   删除的行以 `-` 开头，插入的行以 `+` 开头，各段之前有 `@@ -起始行,行数 +起始行,行数 @@`;
+/// - [`DiffMode::Token`]: 逐词比较，输出完整的右值，删除的部分标注为 `[-…-]`, 插入的部分标注为 `{+…+}`.
+///
+/// 着色时删除的部分为红色，插入的部分为绿色；逐词比较时以颜色代替标注。
+/// 格式化参数中的 `#` 标志与精度传递给处理器，因而 `{:#}` 比较的是 `{:#?}` 的输出。
+///
+/// # 字段
+/// - `left`、`right`: 比较的两个值。
+/// - `mode`: 比较的粒度，默认为 [`DiffMode::Line`].
+/// - `context`: 逐行比较时保留的上下文行数，默认为 `3`.
+/// - `color`: 是否以 ANSI 转义序列着色，默认为 `false`.
+/// - `handler`: 格式化两个值的处理器，默认为 [`DebugProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{Diff, DiffMode};
+/// #[derive(Debug)]
+/// struct Config {
+///     name: &'static str,
+///     port: u16,
+///     debug: bool,
+/// }
+/// let a = Config { name: "app", port: 80, debug: false };
+/// let b = Config { name: "app", port: 8080, debug: false };
+/// assert_eq!(
+///     format!("{:#}", Diff::new(&a, &b).with_context(1)),
+///     "\
+/// @@ -2,3 +2,3 @@
+///      name: \"app\",
+/// -    port: 80,
+/// +    port: 8080,
+///      debug: false,"
+/// );
+/// assert_eq!(
+///     format!("{}", Diff::new(&a, &b).with_mode(DiffMode::Token)),
+///     "Config { name: \"app\", port: [-80-]{+8080+}, debug: false }"
+/// );
+/// ```
+/// # 注意
+/// - 需要启用 `alloc` 特性。
+/// - 两个输出相同时，逐行比较不输出任何内容。
+/// - 比较的耗时与输出长度和差异大小之积成正比。
+#[derive(Debug)]
+pub struct Diff<'a, A: ?::core::marker::Sized, B: ?::core::marker::Sized = A, Handler = DebugProxy>
+{
+    pub left: &'a A,
+    pub right: &'a B,
+    pub mode: DiffMode,
+    pub context: usize,
+    pub color: bool,
+    pub handler: Handler,
+}
+
+impl<A, B, Handler> ::core::clone::Clone for Diff<'_, A, B, Handler>
+where
+    A: ?::core::marker::Sized,
+    B: ?::core::marker::Sized,
+    Handler: ::core::marker::Copy,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A, B, Handler> ::core::marker::Copy for Diff<'_, A, B, Handler>
+where
+    A: ?::core::marker::Sized,
+    B: ?::core::marker::Sized,
+    Handler: ::core::marker::Copy,
+{
+}
+
+impl<'a, A, B> Diff<'a, A, B>
+where
+    A: ?::core::marker::Sized,
+    B: ?::core::marker::Sized,
+{
+    /// 逐行比较 `left` 与 `right` 的 [`Debug`](::core::fmt::Debug) 输出，保留三行上下文，不着色。
+    #[inline]
+    pub const fn new(left: &'a A, right: &'a B) -> Self {
+        Self {
+            left,
+            right,
+            mode: DiffMode::Line,
+            context: 3,
+            color: false,
+            handler: DebugProxy,
+        }
+    }
+}
+
+impl<'a, A, B, Handler> Diff<'a, A, B, Handler>
+where
+    A: ?::core::marker::Sized,
+    B: ?::core::marker::Sized,
+{
+    /// 替换比较的粒度。
+    #[inline]
+    pub fn with_mode(self, mode: DiffMode) -> Self {
+        Self { mode, ..self }
+    }
+    /// 替换上下文行数。
+    #[inline]
+    pub fn with_context(self, context: usize) -> Self {
+        Self { context, ..self }
+    }
+    /// 设置是否着色。
+    #[inline]
+    pub fn with_color(self, color: bool) -> Self {
+        Self { color, ..self }
+    }
+    /// 替换格式化两个值的处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> Diff<'a, A, B, NewHandler> {
+        Diff {
+            left: self.left,
+            right: self.right,
+            mode: self.mode,
+            context: self.context,
+            color: self.color,
+            handler,
+        }
+    }
+}
+
+impl<A, B, Handler> Diff<'_, A, B, Handler>
+where
+    A: ?::core::marker::Sized,
+    B: ?::core::marker::Sized,
+{
+    /// 以 `color` 着色输出 `s`.
+    fn paint(
+        &self,
+        f: &mut ::core::fmt::Formatter<'_>,
+        color: &str,
+        s: impl ::core::fmt::Display,
+    ) -> ::core::fmt::Result {
+        match self.color {
+            true => write!(f, "{}{}{}", color, s, RESET),
+            false => write!(f, "{}", s),
+        }
+    }
+    fn fmt_lines(
+        &self,
+        f: &mut ::core::fmt::Formatter<'_>,
+        left: &str,
+        right: &str,
+    ) -> ::core::fmt::Result {
+        let a: Vec<&str> = left.lines().collect();
+        let b: Vec<&str> = right.lines().collect();
+        let ops = myers(&a, &b);
+        let is_change = |op: &Op| !matches!(op, Op::Equal(..));
+        let mut next = 0;
+        while let Some(first) = ops[next..].iter().position(is_change).map(|i| i + next) {
+            // 间隔不超过两倍上下文的变化合并为一段
+            let mut last = first;
+            while let Some(gap) = ops[last + 1..].iter().position(is_change)
+                && gap <= 2 * self.context
+            {
+                last += gap + 1;
+            }
+            let start = first.saturating_sub(self.context).max(next);
+            let end = (last + self.context + 1).min(ops.len());
+            let hunk = &ops[start..end];
+            let count = |ops: &[Op], old: bool| {
+                ops.iter()
+                    .filter(|op| match op {
+                        Op::Equal(..) => true,
+                        Op::Delete(_) => old,
+                        Op::Insert(_) => !old,
+                    })
+                    .count()
+            };
+            let (old_before, new_before) =
+                (count(&ops[..start], true), count(&ops[..start], false));
+            let (old_len, new_len) = (count(hunk, true), count(hunk, false));
+            if next != 0 {
+                f.write_str("\n")?;
+            }
+            let header = crate::FmtFn(|f: &mut ::core::fmt::Formatter<'_>| {
+                write!(
+                    f,
+                    "@@ -{},{} +{},{} @@",
+                    old_before + (old_len != 0) as usize,
+                    old_len,
+                    new_before + (new_len != 0) as usize,
+                    new_len
+                )
+            });
+            match self.color {
+                true => write!(f, "{}{}{}", CYAN, header, RESET)?,
+                false => write!(f, "{}", header)?,
+            }
+            for op in hunk {
+                f.write_str("\n")?;
+                match *op {
+                    Op::Equal(i, _) => write!(f, " {}", a[i])?,
+                    Op::Delete(i) => self.paint(f, RED, format_args!("-{}", a[i]))?,
+                    Op::Insert(j) => self.paint(f, GREEN, format_args!("+{}", b[j]))?,
+                }
+            }
+            next = end;
+        }
+        Ok(())
+    }
+    fn fmt_tokens(
+        &self,
+        f: &mut ::core::fmt::Formatter<'_>,
+        left: &str,
+        right: &str,
+    ) -> ::core::fmt::Result {
+        let a = tokens(left);
+        let b = tokens(right);
+        let ops = myers(&a, &b);
+        let mut i = 0;
+        while i < ops.len() {
+            if let Op::Equal(_, j) = ops[i] {
+                f.write_str(b[j])?;
+                i += 1;
+                continue;
+            }
+            // 连续的删除与插入各自合并，先删除后插入
+            let run = ops[i..]
+                .iter()
+                .position(|op| matches!(op, Op::Equal(..)))
+                .map_or(ops.len(), |len| i + len);
+            for (old, (open, close, color)) in
+                [(true, ("[-", "-]", RED)), (false, ("{+", "+}", GREEN))]
+            {
+                let mut parts = ops[i..run].iter().filter_map(|op| match (*op, old) {
+                    (Op::Delete(i), true) => Some(a[i]),
+                    (Op::Insert(j), false) => Some(b[j]),
+                    _ => None,
+                });
+                let Some(first) = parts.next() else {
+                    continue;
+                };
+                if self.color {
+                    f.write_str(color)?;
+                } else {
+                    f.write_str(open)?;
+                }
+                f.write_str(first)?;
+                parts.try_for_each(|part| f.write_str(part))?;
+                f.write_str(if self.color { RESET } else { close })?;
+            }
+            i = run;
+        }
+        Ok(())
+    }
+}
+
+impl<A, B, Handler> ::core::fmt::Display for Diff<'_, A, B, Handler>
+where
+    A: ?::core::marker::Sized,
+    B: ?::core::marker::Sized,
+    Handler: FmtHandler<A> + FmtHandler<B>,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let options = FmtOptions::of(f);
+        let mut left = String::new();
+        options.write_by(&mut left, self.left, self.handler, FmtTrait::Display)?;
+        let mut right = String::new();
+        options.write_by(&mut right, self.right, self.handler, FmtTrait::Display)?;
+        match self.mode {
+            DiffMode::Line => self.fmt_lines(f, &left, &right),
+            DiffMode::Token => self.fmt_tokens(f, &left, &right),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DisplayProxy;
+
+    #[test]
+    fn test_myers() {
+        let a: Vec<char> = "ABCABBA".chars().collect();
+        let b: Vec<char> = "CBABAC".chars().collect();
+        let ops = myers(&a, &b);
+        // 最短编辑距离为 5, 且编辑序列能将 a 变为 b
+        let edits = ops.iter().filter(|op| !matches!(op, Op::Equal(..))).count();
+        assert_eq!(edits, 5);
+        let rebuilt: String = ops
+            .iter()
+            .filter_map(|op| match *op {
+                Op::Equal(_, j) | Op::Insert(j) => Some(b[j]),
+                Op::Delete(_) => None,
+            })
+            .collect();
+        assert_eq!(rebuilt, "CBABAC");
+        assert!(myers::<char>(&[], &[]).is_empty());
+        assert_eq!(myers(&[], &['x']), [Op::Insert(0)]);
+
+        // 测试长序列中的少量差异
+        let a: Vec<u32> = (0..5000).collect();
+        let mut b = a.clone();
+        b.remove(10);
+        b.insert(2500, 9999);
+        b[4000] = 7777;
+        let ops = myers(&a, &b);
+        let edits = ops.iter().filter(|op| !matches!(op, Op::Equal(..))).count();
+        assert_eq!(edits, 4);
+        let (mut i, mut j) = (0, 0);
+        for op in &ops {
+            match *op {
+                Op::Equal(x, y) => {
+                    assert_eq!((x, y, a[x]), (i, j, b[y]));
+                    (i, j) = (i + 1, j + 1);
+                }
+                Op::Delete(x) => {
+                    assert_eq!(x, i);
+                    i += 1;
+                }
+                Op::Insert(y) => {
+                    assert_eq!(y, j);
+                    j += 1;
+                }
+            }
+        }
+        assert_eq!((i, j), (a.len(), b.len()));
+    }
+
+    #[test]
+    fn test_diff() {
+        // 测试分段与上下文
+        let a = "1\n2\n3\n4\n5\n6\n7\n8\n9";
+        let b = "1\nX\n3\n4\n5\n6\n7\n8";
+        let diff = Diff::new(a, b).with_handler(DisplayProxy).with_context(1);
+        assert_eq!(
+            format!("{}", diff),
+            "@@ -1,3 +1,3 @@\n 1\n-2\n+X\n 3\n@@ -8,2 +8,1 @@\n 8\n-9"
+        );
+        assert_eq!(
+            format!("{}", diff.with_context(3)),
+            "@@ -1,9 +1,8 @@\n 1\n-2\n+X\n 3\n 4\n 5\n 6\n 7\n 8\n-9"
+        );
+        assert_eq!(
+            format!("{}", Diff::new("", "a").with_handler(DisplayProxy)),
+            "@@ -0,0 +1,1 @@\n+a"
+        );
+        assert_eq!(format!("{}", Diff::new(&1, &1)), "");
+
+        // 测试着色与逐词比较
+        let diff = Diff::new("a\nb", "a\nc")
+            .with_handler(DisplayProxy)
+            .with_color(true);
+        assert_eq!(
+            format!("{}", diff),
+            "\x1b[36m@@ -1,2 +1,2 @@\x1b[0m\n a\n\x1b[31m-b\x1b[0m\n\x1b[32m+c\x1b[0m"
+        );
+        let diff = Diff::new(&[1, 2, 3][..], &[1, 4, 3, 5][..]).with_mode(DiffMode::Token);
+        assert_eq!(format!("{}", diff), "[1, [-2-]{+4+}, 3{+, 5+}]");
+        assert_eq!(
+            format!("{}", diff.with_color(true)),
+            "[1, \x1b[31m2\x1b[0m\x1b[32m4\x1b[0m, 3\x1b[32m, 5\x1b[0m]"
+        );
+    }
+}
@@ -0,0 +1,395 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::FmtHandler;
+
+/// 将 ASCII 字节块写入格式化器。
+#[inline]
+fn write_ascii(f: &mut ::core::fmt::Formatter<'_>, chunk: &[u8]) -> ::core::fmt::Result {
+    f.write_str(::core::str::from_utf8(chunk).map_err(|_| ::core::fmt::Error)?)
+}
+
+/// [`Base64`] 的字母表
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Base64Alphabet {
+    /// RFC 4648 标准字母表，使用 `+` 和 `/`.
+    #[default]
+    Standard,
+    /// RFC 4648 URL 及文件名安全字母表，使用 `-` 和 `_`.
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    #[inline]
+    const fn table(self) -> &'static [u8; 64] {
+        match self {
+            Self::Standard => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Self::UrlSafe => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+        }
+    }
+}
+
+/// 将字节数据以 Base64 编码输出的格式化处理器
+///
+/// 按 3 字节一组编码并直接写入格式化器，不分配内存。
+///
+/// # 字段
+/// - `alphabet`: 字母表，见 [`Base64Alphabet`].
+/// - `pad`: 是否以 `=` 补齐末组。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{Base64, Base64Alphabet, Base64Url, FmtAs, FmtBy};
+/// let data = b"\xfb\xffhi";
+/// assert_eq!(format!("{}", data.fmt_as::<Base64>()), "+/9oaQ==");
+/// assert_eq!(format!("{}", data.fmt_as::<Base64Url>()), "-_9oaQ");
+/// let base64 = Base64 { alphabet: Base64Alphabet::UrlSafe, pad: true };
+/// assert_eq!(format!("{}", data.fmt_by(base64)), "-_9oaQ==");
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef), 如 `[u8]`、`[u8; N]`、`&[u8]`、`str`、`Vec<u8>` 等。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base64 {
+    pub alphabet: Base64Alphabet,
+    pub pad: bool,
+}
+
+impl ::core::default::Default for Base64 {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            alphabet: Base64Alphabet::Standard,
+            pad: true,
+        }
+    }
+}
+
+impl<T> FmtHandler<T> for Base64
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+{
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let table = self.alphabet.table();
+        for chunk in data.as_ref().chunks(3) {
+            let mut group = [0u8; 3];
+            group[..chunk.len()].copy_from_slice(chunk);
+            let bits = u32::from_be_bytes([0, group[0], group[1], group[2]]);
+            let mut out = [b'='; 4];
+            // n 个字节对应 n + 1 个有效字符。
+            for (i, c) in out.iter_mut().enumerate().take(chunk.len() + 1) {
+                *c = table[(bits >> (18 - 6 * i) & 0x3f) as usize];
+            }
+            let len = if self.pad { 4 } else { chunk.len() + 1 };
+            write_ascii(f, &out[..len])?;
+        }
+        Ok(())
+    }
+}
+
+/// 以 URL 安全字母表、不补齐的 Base64 编码输出字节数据的格式化处理器
+///
+/// 等价于 `Base64 { alphabet: Base64Alphabet::UrlSafe, pad: false }`, 常用于令牌与 JWT 等场合。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{Base64Url, FmtAs};
+/// let key = [0xfau8, 0xce, 0xb0, 0x0c];
+/// assert_eq!(format!("{}", key.fmt_as::<Base64Url>()), "-s6wDA");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Base64Url;
+
+impl<T> FmtHandler<T> for Base64Url
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        Base64 {
+            alphabet: Base64Alphabet::UrlSafe,
+            pad: false,
+        }
+        .fmt(data, f)
+    }
+}
+
+/// 将字节数据以 Base32 编码（RFC 4648 标准字母表）输出的格式化处理器
+///
+/// 按 5 字节一组编码并直接写入格式化器，不分配内存。
+///
+/// # 字段
+/// - `pad`: 是否以 `=` 补齐末组。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{Base32, FmtAs, FmtBy};
+/// assert_eq!(format!("{}", b"foobar".fmt_as::<Base32>()), "MZXW6YTBOI======");
+/// assert_eq!(format!("{}", b"foobar".fmt_by(Base32 { pad: false })), "MZXW6YTBOI");
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base32 {
+    pub pad: bool,
+}
+
+impl ::core::default::Default for Base32 {
+    #[inline]
+    fn default() -> Self {
+        Self { pad: true }
+    }
+}
+
+impl<T> FmtHandler<T> for Base32
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+{
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        const TABLE: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+        for chunk in data.as_ref().chunks(5) {
+            let mut group = [0u8; 8];
+            group[3..3 + chunk.len()].copy_from_slice(chunk);
+            let bits = u64::from_be_bytes(group);
+            let mut out = [b'='; 8];
+            // n 个字节对应 ⌈8n / 5⌉ 个有效字符。
+            let len = (chunk.len() * 8).div_ceil(5);
+            for (i, c) in out.iter_mut().enumerate().take(len) {
+                *c = TABLE[(bits >> (35 - 5 * i) & 0x1f) as usize];
+            }
+            write_ascii(f, &out[..if self.pad { 8 } else { len }])?;
+        }
+        Ok(())
+    }
+}
+
+/// 将字节数据以 Base58 编码（比特币字母表）输出的格式化处理器
+///
+/// Base58 将整个输入视作一个大整数进行进制转换，无法逐块编码。输入长度（不计前导零字节）不超过
+/// [`Base58::MAX_LEN`] 时使用栈上的定长缓冲区，不分配内存；未启用 `alloc` 特性时，更长的输入返回 [`Error`](::core::fmt::Error)
+/// 而不输出任何内容。每个前导零字节编码为一个 `1`.
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{Base58, FmtAs};
+/// assert_eq!(format!("{}", b"hello world".fmt_as::<Base58>()), "StV1DL6CwTryKyV");
+/// assert_eq!(format!("{}", [0u8, 0, 0x28, 0x7f].fmt_as::<Base58>()), "1145k");
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef);
+/// - 输入长度超过 [`Base58::MAX_LEN`] 时，启用 `alloc` 特性则在堆上分配缓冲区；
+///   否则返回错误，此时 `format!` 等会 panic, 请改用 [`write!`] 处理错误。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Base58;
+
+impl Base58 {
+    /// 不分配内存即可完整编码的最大输入长度（不计前导零字节）。
+    pub const MAX_LEN: usize = 256;
+    const TABLE: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    /// 编码 `len` 个字节所需的 58 进制位数的上限。
+    const fn capacity(len: usize) -> usize {
+        // log(256) / log(58) ≈ 1.3657
+        len * 1366 / 1000 + 1
+    }
+
+    /// 将 `data` 编码至 `digits`, 返回所用的字符数。`digits` 的长度不得小于 [`Base58::capacity`].
+    fn encode(data: &[u8], digits: &mut [u8]) -> usize {
+        // 以小端序存放 58 进制的各位。
+        let mut len = 0;
+        for &byte in data {
+            let mut carry = byte as u32;
+            for digit in &mut digits[..len] {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry > 0 {
+                digits[len] = (carry % 58) as u8;
+                len += 1;
+                carry /= 58;
+            }
+        }
+        let digits = &mut digits[..len];
+        digits.reverse();
+        for digit in digits.iter_mut() {
+            *digit = Self::TABLE[*digit as usize];
+        }
+        len
+    }
+}
+
+impl<T> FmtHandler<T> for Base58
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+{
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let data = data.as_ref();
+        let zeros = data.iter().take_while(|&&b| b == 0).count();
+        let data = &data[zeros..];
+        #[cfg(not(feature = "alloc"))]
+        if data.len() > Self::MAX_LEN {
+            return Err(::core::fmt::Error);
+        }
+        for _ in 0..zeros {
+            f.write_str("1")?;
+        }
+        #[cfg(feature = "alloc")]
+        if data.len() > Self::MAX_LEN {
+            let mut digits = ::alloc::vec![0u8; Self::capacity(data.len())];
+            let len = Self::encode(data, &mut digits);
+            return write_ascii(f, &digits[..len]);
+        }
+        let mut digits = [0u8; Self::capacity(Self::MAX_LEN)];
+        let len = Self::encode(data, &mut digits);
+        write_ascii(f, &digits[..len])
+    }
+}
+
+/// 将字节数据以 Z85（ZeroMQ Base85）编码输出的格式化处理器
+///
+/// 按 4 字节一组编码为 5 个字符并直接写入格式化器，不分配内存。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, Z85};
+/// let data = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+/// assert_eq!(format!("{}", data.fmt_as::<Z85>()), "HelloWorld");
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef);
+/// - Z85 规范要求输入长度为 4 的倍数；对于末尾不足 4 字节的 n 个字节，按 Ascii85 的惯例补零编码后只输出前 n + 1 个字符。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Z85;
+
+impl<T> FmtHandler<T> for Z85
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+{
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        const TABLE: &[u8; 85] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+        for chunk in data.as_ref().chunks(4) {
+            let mut group = [0u8; 4];
+            group[..chunk.len()].copy_from_slice(chunk);
+            let mut value = u32::from_be_bytes(group);
+            let mut out = [0u8; 5];
+            for c in out.iter_mut().rev() {
+                *c = TABLE[(value % 85) as usize];
+                value /= 85;
+            }
+            write_ascii(f, &out[..chunk.len() + 1])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy};
+
+    #[test]
+    fn test_base64() {
+        // RFC 4648 测试向量
+        let cases = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(format!("{}", input.fmt_as::<Base64>()), expected);
+            assert_eq!(
+                format!("{}", input.fmt_as::<Base64Url>()),
+                expected.trim_end_matches('=')
+            );
+        }
+        let proxy = [0xffu8, 0xfe].fmt_by(Base64 {
+            alphabet: Base64Alphabet::Standard,
+            pad: false,
+        });
+        assert_eq!(format!("{}", proxy), "//4");
+    }
+
+    #[test]
+    fn test_base32() {
+        // RFC 4648 测试向量
+        let cases = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(format!("{}", input.fmt_as::<Base32>()), expected);
+        }
+    }
+
+    #[test]
+    fn test_base58_and_z85() {
+        assert_eq!(format!("{}", b"".fmt_as::<Base58>()), "");
+        assert_eq!(format!("{}", [0u8; 3].fmt_as::<Base58>()), "111");
+        assert_eq!(
+            format!("{}", b"The quick brown fox".fmt_as::<Base58>()),
+            "GTFcJ7VjJ5DH28yrRznc2UeiV5"
+        );
+        // 测试超出栈上缓冲区的输入
+        use ::core::fmt::Write;
+        let long = [7u8; Base58::MAX_LEN + 44];
+        let mut encoded = String::new();
+        let result = write!(encoded, "{}", long.fmt_as::<Base58>());
+        if cfg!(feature = "alloc") {
+            assert!(result.is_ok());
+            // 解码后应与输入相同
+            let mut decoded = vec![0u8; long.len()];
+            for c in encoded.bytes() {
+                let mut carry = Base58::TABLE.iter().position(|&d| d == c).unwrap() as u32;
+                for byte in decoded.iter_mut().rev() {
+                    carry += (*byte as u32) * 58;
+                    *byte = carry as u8;
+                    carry >>= 8;
+                }
+                assert_eq!(carry, 0);
+            }
+            assert_eq!(decoded, long);
+        } else {
+            // 未启用 `alloc` 特性时返回错误，且不输出截断的结果
+            assert!(result.is_err());
+            assert_eq!(encoded, "");
+        }
+        // 前导零字节不计入长度
+        let mut zeros = [0u8; Base58::MAX_LEN + 1];
+        zeros[Base58::MAX_LEN] = 1;
+        assert_eq!(
+            format!("{}", zeros.fmt_as::<Base58>()),
+            "1".repeat(Base58::MAX_LEN) + "2"
+        );
+
+        assert_eq!(format!("{}", b"".fmt_as::<Z85>()), "");
+        assert_eq!(format!("{}", [0x86u8, 0x4f, 0xd2].fmt_as::<Z85>()), "Helj");
+    }
+}
@@ -0,0 +1,577 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    DisplayProxy, FmtHandler, FmtTrait, TextFilter, WidthCounter, fmt_filtered_with, pad,
+    pad_output, str_width, write_escaped,
+};
+
+macro_rules! define_escape_handler {
+    (
+        $(#[$meta: meta])*
+        $handler: ident, $filter: ident, $needs_escape: expr, $escape: expr
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $handler<Handler = DisplayProxy>(pub Handler);
+
+        struct $filter;
+
+        impl TextFilter for $filter {
+            #[inline]
+            fn write_str(
+                &mut self,
+                out: &mut ::core::fmt::Formatter<'_>,
+                s: &str,
+            ) -> ::core::fmt::Result {
+                write_escaped(out, s, $needs_escape, $escape)
+            }
+        }
+
+        impl<T, Handler> FmtHandler<T> for $handler<Handler>
+        where
+            T: ?::core::marker::Sized,
+            Handler: FmtHandler<T>,
+        {
+            #[inline]
+            fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.fmt_trait(data, f, FmtTrait::Display)
+            }
+            #[inline]
+            fn fmt_trait(
+                &self,
+                data: &T,
+                f: &mut ::core::fmt::Formatter<'_>,
+                fmt_trait: FmtTrait,
+            ) -> ::core::fmt::Result {
+                pad_output(f, |f, options| {
+                    fmt_filtered_with($filter, data, self.0, f, options, fmt_trait)
+                })
+            }
+        }
+    };
+}
+
+define_escape_handler!(
+    /// 对内部处理器的输出进行 HTML 转义的格式化处理器
+    ///
+    /// 转义 `&`、`<`、`>`、`"` 与 `'`, 结果可安全地用于元素内容及带引号的属性值。宽度与对齐作用于转义后的输出。
+    ///
+    /// # 类型参数
+    /// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+    ///
+    /// # 示例
+    /// ``` rust
+    /// use wfu::{DebugProxy, FmtAs, FmtBy, HtmlEscape};
+    /// let s = "<a href='x'>Tom & Jerry</a>";
+    /// assert_eq!(
+    ///     format!("{}", s.fmt_as::<HtmlEscape>()),
+    ///     "&lt;a href=&#39;x&#39;&gt;Tom &amp; Jerry&lt;/a&gt;"
+    /// );
+    /// assert_eq!(format!("{}", "<>".fmt_by(HtmlEscape(DebugProxy))), "&quot;&lt;&gt;&quot;");
+    /// ```
+    HtmlEscape,
+    HtmlFilter,
+    |c| matches!(c, '&' | '<' | '>' | '"' | '\''),
+    |c, out| out.write_str(match c {
+        '&' => "&amp;",
+        '<' => "&lt;",
+        '>' => "&gt;",
+        '"' => "&quot;",
+        _ => "&#39;",
+    })
+);
+
+define_escape_handler!(
+    /// 对内部处理器的输出进行 XML 属性值转义的格式化处理器
+    ///
+    /// 除 `&`、`<`、`>`、`"` 与 `'` 外，还将制表符与换行符转义为字符引用，以免被属性值规范化为空格。
+    /// 宽度与对齐作用于转义后的输出。
+    ///
+    /// # 类型参数
+    /// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+    ///
+    /// # 示例
+    /// ``` rust
+    /// use wfu::{FmtAs, XmlAttrEscape};
+    /// let s = "a\t\"b\"\r\n";
+    /// assert_eq!(format!("{}", s.fmt_as::<XmlAttrEscape>()), "a&#9;&quot;b&quot;&#13;&#10;");
+    /// ```
+    XmlAttrEscape,
+    XmlAttrFilter,
+    |c| matches!(c, '&' | '<' | '>' | '"' | '\'' | '\t' | '\n' | '\r'),
+    |c, out| out.write_str(match c {
+        '&' => "&amp;",
+        '<' => "&lt;",
+        '>' => "&gt;",
+        '"' => "&quot;",
+        '\'' => "&apos;",
+        '\t' => "&#9;",
+        '\n' => "&#10;",
+        _ => "&#13;",
+    })
+);
+
+define_escape_handler!(
+    /// 对内部处理器的输出进行正则表达式转义的格式化处理器
+    ///
+    /// 在所有正则元字符（`\.+*?()|[]{}^$#&-~`）之前添加 `\`, 使结果按字面匹配。宽度与对齐作用于转义后的输出。
+    ///
+    /// # 类型参数
+    /// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+    ///
+    /// # 示例
+    /// ``` rust
+    /// use wfu::{FmtAs, RegexEscape};
+    /// assert_eq!(format!("{}", "1+1=2?".fmt_as::<RegexEscape>()), r"1\+1=2\?");
+    /// ```
+    RegexEscape,
+    RegexFilter,
+    |c| matches!(
+        c,
+        '\\' | '.' | '+' | '*' | '?' | '(' | ')' | '|' | '[' | ']' | '{' | '}' | '^' | '$' | '#'
+            | '&' | '-' | '~'
+    ),
+    |c, out| {
+        use ::core::fmt::Write;
+        out.write_char('\\')?;
+        out.write_char(c)
+    }
+);
+
+/// 将内部处理器的输出转义为 SQL 字符串字面量的格式化处理器
+///
+/// 以 `'` 包围，并将内容中的 `'` 写作 `''`. 宽度与对齐作用于包括引号在内的整体输出。
+///
+/// # 类型参数
+/// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, SqlStr};
+/// assert_eq!(format!("{}", "O'Brien".fmt_as::<SqlStr>()), "'O''Brien'");
+/// ```
+/// # 注意
+/// - 仅处理标准 SQL 的引号规则，不处理特定数据库对反斜杠的扩展；请优先使用参数化查询。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SqlStr<Handler = DisplayProxy>(pub Handler);
+
+struct SqlFilter;
+
+impl TextFilter for SqlFilter {
+    #[inline]
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        write_escaped(out, s, |c| c == '\'', |_, out| out.write_str("''"))
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for SqlStr<Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        pad_output(f, |f, options| {
+            f.write_str("'")?;
+            fmt_filtered_with(SqlFilter, data, self.0, f, options, fmt_trait)?;
+            f.write_str("'")
+        })
+    }
+}
+
+/// 将内部处理器的输出转义为 JSON 字符串的格式化处理器
+///
+/// 转义 `"`、`\` 与所有控制字符，常见控制字符使用简写（如 `\n`），其余使用 `\u00XX`.
+/// 宽度与对齐作用于包括引号在内的整体输出。
+///
+/// # 字段
+/// - `quoted`: 是否以 `"` 包围，默认为 `true`.
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{DisplayProxy, FmtAs, FmtBy, JsonStr};
+/// let s = "say \"hi\"\n\u{1}";
+/// assert_eq!(format!("{}", s.fmt_as::<JsonStr>()), r#""say \"hi\"\n\u0001""#);
+/// let proxy = s.fmt_by(JsonStr { quoted: false, handler: DisplayProxy });
+/// assert_eq!(format!("{}", proxy), r#"say \"hi\"\n\u0001"#);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct JsonStr<Handler = DisplayProxy> {
+    pub quoted: bool,
+    pub handler: Handler,
+}
+
+impl<Handler: ::core::default::Default> ::core::default::Default for JsonStr<Handler> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            quoted: true,
+            handler: Handler::default(),
+        }
+    }
+}
+
+struct JsonFilter;
+
+impl TextFilter for JsonFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        write_escaped(
+            out,
+            s,
+            |c| c == '"' || c == '\\' || c.is_ascii_control(),
+            |c, out| match c {
+                '"' => out.write_str("\\\""),
+                '\\' => out.write_str("\\\\"),
+                '\n' => out.write_str("\\n"),
+                '\r' => out.write_str("\\r"),
+                '\t' => out.write_str("\\t"),
+                '\u{8}' => out.write_str("\\b"),
+                '\u{c}' => out.write_str("\\f"),
+                _ => write!(out, "\\u{:04x}", c as u32),
+            },
+        )
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for JsonStr<Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        pad_output(f, |f, options| {
+            if self.quoted {
+                f.write_str("\"")?;
+            }
+            fmt_filtered_with(JsonFilter, data, self.handler, f, options, fmt_trait)?;
+            if self.quoted {
+                f.write_str("\"")?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// 仅检查内容、不产生输出的过滤器，用于两遍处理中的第一遍。
+struct ScanFilter<P> {
+    predicate: P,
+    /// 需要转义的引号字符。
+    quote: char,
+    /// 是否存在满足 `predicate` 的字符。
+    found: bool,
+    non_empty: bool,
+    width: WidthCounter,
+    /// 引号字符出现的次数。
+    quotes: usize,
+}
+
+impl<P: Fn(char) -> bool> TextFilter for ScanFilter<P> {
+    fn write_str(&mut self, _: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        self.found = self.found || s.chars().any(&self.predicate);
+        self.non_empty = self.non_empty || !s.is_empty();
+        self.quotes += s.matches(self.quote).count();
+        self.width.write_str(s)
+    }
+}
+
+/// 引号规则，见 [`write_quoted`].
+struct Quoting<P, F> {
+    /// 出现满足此条件的字符时加引号。
+    needs_quotes: P,
+    /// 空内容是否加引号。
+    quote_empty: bool,
+    quote: &'static str,
+    /// 内容中每个引号字符转义后增加的显示宽度。
+    escape_extra: usize,
+    /// 转义内容中引号字符的过滤器。
+    filter: F,
+}
+
+/// 先扫描内部处理器的输出，按需以引号包围并转义后输出。宽度作用于包括引号在内的整体输出。
+fn write_quoted<T, Handler, P, F>(
+    data: &T,
+    handler: Handler,
+    f: &mut ::core::fmt::Formatter<'_>,
+    fmt_trait: FmtTrait,
+    quoting: Quoting<P, F>,
+) -> ::core::fmt::Result
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+    P: Fn(char) -> bool,
+    F: TextFilter,
+{
+    let options = crate::FmtOptions::of(f);
+    let quote = quoting.quote.chars().next().unwrap_or_default();
+    let mut scan = ScanFilter {
+        predicate: quoting.needs_quotes,
+        quote,
+        found: false,
+        non_empty: false,
+        width: WidthCounter::default(),
+        quotes: 0,
+    };
+    fmt_filtered_with(&mut scan, data, handler, f, options, fmt_trait)?;
+    let width = f.width().unwrap_or(0);
+    if !scan.found && (scan.non_empty || !quoting.quote_empty) {
+        return pad(f, width, scan.width.0, |f| {
+            options.write_by(f, data, handler, fmt_trait)
+        });
+    }
+    let quoted_width =
+        scan.width.0 + 2 * str_width(quoting.quote) + scan.quotes * quoting.escape_extra;
+    pad(f, width, quoted_width, |f| {
+        f.write_str(quoting.quote)?;
+        fmt_filtered_with(quoting.filter, data, handler, f, options, fmt_trait)?;
+        f.write_str(quoting.quote)
+    })
+}
+
+/// 将内部处理器的输出转义为 CSV 字段的格式化处理器
+///
+/// 遵循 RFC 4180: 仅当内容包含 `,`、`"`、`\r` 或 `\n` 时才以 `"` 包围，并将内容中的 `"` 写作 `""`.
+/// 为判断是否需要引号，内部处理器会被调用两次，不分配内存。宽度与对齐作用于包括引号在内的整体输出。
+///
+/// # 类型参数
+/// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{CsvField, FmtAs};
+/// assert_eq!(format!("{}", "plain".fmt_as::<CsvField>()), "plain");
+/// assert_eq!(format!("{}", "a, \"b\"".fmt_as::<CsvField>()), "\"a, \"\"b\"\"\"");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CsvField<Handler = DisplayProxy>(pub Handler);
+
+struct CsvFilter;
+
+impl TextFilter for CsvFilter {
+    #[inline]
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        write_escaped(out, s, |c| c == '"', |_, out| out.write_str("\"\""))
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for CsvField<Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        let quoting = Quoting {
+            needs_quotes: |c| matches!(c, ',' | '"' | '\r' | '\n'),
+            quote_empty: false,
+            quote: "\"",
+            escape_extra: 1,
+            filter: CsvFilter,
+        };
+        write_quoted(data, self.0, f, fmt_trait, quoting)
+    }
+}
+
+/// 将内部处理器的输出按 POSIX shell 单引号规则转义的格式化处理器
+///
+/// 内容仅由安全字符（ASCII 字母、数字及 `@%+=:,./_-`）组成时原样输出，否则以 `'` 包围，
+/// 并将内容中的 `'` 写作 `'\''`; 空内容输出为 `''`. 为判断是否需要引号，内部处理器会被调用两次，不分配内存。
+/// 宽度与对齐作用于包括引号在内的整体输出。
+///
+/// # 类型参数
+/// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, ShellQuote};
+/// assert_eq!(format!("{}", "file.txt".fmt_as::<ShellQuote>()), "file.txt");
+/// assert_eq!(format!("{}", "it's $HOME".fmt_as::<ShellQuote>()), r"'it'\''s $HOME'");
+/// assert_eq!(format!("{}", "".fmt_as::<ShellQuote>()), "''");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShellQuote<Handler = DisplayProxy>(pub Handler);
+
+struct ShellFilter;
+
+impl TextFilter for ShellFilter {
+    #[inline]
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        write_escaped(out, s, |c| c == '\'', |_, out| out.write_str("'\\''"))
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for ShellQuote<Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        let quoting = Quoting {
+            needs_quotes: |c: char| !(c.is_ascii_alphanumeric() || "@%+=:,./_-".contains(c)),
+            quote_empty: true,
+            quote: "'",
+            escape_extra: 3,
+            filter: ShellFilter,
+        };
+        write_quoted(data, self.0, f, fmt_trait, quoting)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DebugProxy, FmtAs, FmtBy, Joined};
+
+    #[test]
+    fn test_escape_handlers() {
+        // 测试宽度作用于转义后的输出
+        assert_eq!(
+            format!("[{:>8}]", "a<b".fmt_as::<HtmlEscape>()),
+            "[  a&lt;b]"
+        );
+        assert_eq!(
+            format!("[{:*^8}]", "a.b".fmt_as::<RegexEscape>()),
+            r"[**a\.b**]"
+        );
+        assert_eq!(format!("[{:>8}]", "ab".fmt_as::<SqlStr>()), "[    'ab']");
+        let s = "<'&'>";
+        assert_eq!(
+            format!("{}", s.fmt_as::<HtmlEscape>()),
+            "&lt;&#39;&amp;&#39;&gt;"
+        );
+        assert_eq!(
+            format!("{}", s.fmt_as::<XmlAttrEscape>()),
+            "&lt;&apos;&amp;&apos;&gt;"
+        );
+        assert_eq!(format!("{}", s.fmt_as::<SqlStr>()), "'<''&''>'");
+        assert_eq!(
+            format!("{}", "a.b*[c]".fmt_as::<RegexEscape>()),
+            r"a\.b\*\[c\]"
+        );
+
+        // 测试与其他处理器组合
+        let items = ["<b>", "&"];
+        let proxy = items.fmt_by(HtmlEscape(Joined(", ")));
+        assert_eq!(format!("{}", proxy), "&lt;b&gt;, &amp;");
+    }
+
+    #[test]
+    fn test_json_str() {
+        assert_eq!(format!("{}", "".fmt_as::<JsonStr>()), r#""""#);
+        assert_eq!(
+            format!("{}", "tab\there\\ \u{7f}".fmt_as::<JsonStr>()),
+            r#""tab\there\\ \u007f""#
+        );
+        // 内部处理器为 DebugProxy 时，转义的是 Debug 输出
+        let proxy = "a\"b".fmt_by(JsonStr {
+            quoted: true,
+            handler: DebugProxy,
+        });
+        assert_eq!(format!("{}", proxy), r#""\"a\\\"b\"""#);
+
+        // 测试宽度作用于引号之外
+        assert_eq!(
+            format!("[{:>8}]", "ab".fmt_as::<JsonStr>()),
+            r#"[    "ab"]"#
+        );
+        let proxy = "a\n".fmt_by(JsonStr {
+            quoted: false,
+            handler: DisplayProxy,
+        });
+        assert_eq!(format!("[{:-<5}]", proxy), r"[a\n--]");
+    }
+
+    #[test]
+    fn test_quoting_handlers() {
+        assert_eq!(format!("{}", 42.fmt_as::<CsvField>()), "42");
+        assert_eq!(format!("{}", "".fmt_as::<CsvField>()), "");
+        assert_eq!(format!("{}", "a\nb".fmt_as::<CsvField>()), "\"a\nb\"");
+
+        assert_eq!(format!("{}", "a b".fmt_as::<ShellQuote>()), "'a b'");
+        assert_eq!(format!("{}", "'".fmt_as::<ShellQuote>()), r"''\'''");
+        assert_eq!(
+            format!("{}", "--name=x/y.z".fmt_as::<ShellQuote>()),
+            "--name=x/y.z"
+        );
+
+        // 测试宽度作用于引号之外
+        assert_eq!(format!("[{:8}]", "ab".fmt_as::<ShellQuote>()), "[ab      ]");
+        assert_eq!(
+            format!("[{:>9}]", "a'b".fmt_as::<ShellQuote>()),
+            "[ 'a'\\''b']"
+        );
+        assert_eq!(format!("[{:^4}]", "".fmt_as::<ShellQuote>()), "[ '' ]");
+        assert_eq!(
+            format!("[{:>6}]", "a,\"".fmt_as::<CsvField>()),
+            "[\"a,\"\"\"]"
+        );
+        assert_eq!(
+            format!("[{:-<6}]", "a,b".fmt_as::<CsvField>()),
+            "[\"a,b\"-]"
+        );
+
+        // 测试内部处理器的调用次数
+        let calls = ::core::cell::Cell::new(0);
+        let counted = crate::FmtFn(|f: &mut ::core::fmt::Formatter<'_>| {
+            calls.set(calls.get() + 1);
+            f.write_str("safe")
+        });
+        assert_eq!(format!("{}", counted.fmt_as::<ShellQuote>()), "safe");
+        assert_eq!(calls.get(), 2);
+    }
+}
@@ -0,0 +1,336 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{FmtFn, FmtHandler, FmtTrait};
+
+/// 格式化选项的快照
+///
+/// 过滤类处理器需将内部处理器的输出写入自己的 [`Write`](::core::fmt::Write) 实现，
+/// 此时无法直接复用外层的 [`Formatter`](::core::fmt::Formatter), 只能按快照重建其选项。
+///
+/// # 注意
+/// - 仅保留 `+`、`#` 与 `0` 标志及精度。宽度、对齐与填充字符不传给内部处理器，
+///   由外层处理器按输出的显示宽度自行填充。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FmtOptions {
+    pub sign_plus: bool,
+    pub alternate: bool,
+    pub zero_pad: bool,
+    pub precision: Option<usize>,
+}
+
+/// 按 `options` 逐项选定格式说明的各部分，最终以拼接而成的格式字符串写入。
+macro_rules! write_with_options {
+    ($w: ident, $v: ident, $options: ident) => {
+        write_with_options!(@sign $w, $v, $options, [])
+    };
+    (@sign $w: ident, $v: ident, $o: ident, [$($spec: tt)*]) => {
+        match $o.sign_plus {
+            false => write_with_options!(@alternate $w, $v, $o, [$($spec)*]),
+            true => write_with_options!(@alternate $w, $v, $o, [$($spec)* "+"]),
+        }
+    };
+    (@alternate $w: ident, $v: ident, $o: ident, [$($spec: tt)*]) => {
+        match $o.alternate {
+            false => write_with_options!(@zero $w, $v, $o, [$($spec)*]),
+            true => write_with_options!(@zero $w, $v, $o, [$($spec)* "#"]),
+        }
+    };
+    (@zero $w: ident, $v: ident, $o: ident, [$($spec: tt)*]) => {
+        match $o.zero_pad {
+            false => write_with_options!(@precision $w, $v, $o, [$($spec)*]),
+            true => write_with_options!(@precision $w, $v, $o, [$($spec)* "0"]),
+        }
+    };
+    (@precision $w: ident, $v: ident, $o: ident, [$($spec: tt)*]) => {
+        match $o.precision {
+            None => write!($w, concat!("{:", $($spec,)* "}"), $v),
+            Some(precision) => write!(
+                $w,
+                concat!("{:", $($spec,)* ".precision$}"),
+                $v,
+                precision = precision
+            ),
+        }
+    };
+}
+
+impl FmtOptions {
+    /// 获取 `f` 的选项快照。
+    #[inline]
+    pub fn of(f: &::core::fmt::Formatter<'_>) -> Self {
+        Self {
+            sign_plus: f.sign_plus(),
+            alternate: f.alternate(),
+            zero_pad: f.sign_aware_zero_pad(),
+            precision: f.precision(),
+        }
+    }
+    /// 以这些选项，经由 `fmt_trait` 将 `handler` 对 `data` 的格式化结果写入 `w`.
+    pub fn write_by<W, T, H>(
+        self,
+        w: &mut W,
+        data: &T,
+        handler: H,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result
+    where
+        W: ::core::fmt::Write + ?::core::marker::Sized,
+        T: ?::core::marker::Sized,
+        H: FmtHandler<T>,
+    {
+        let v = FmtFn(|f: &mut ::core::fmt::Formatter<'_>| handler.fmt_trait(data, f, fmt_trait));
+        write_with_options!(w, v, self)
+    }
+}
+
+/// 流式文本过滤器
+///
+/// 过滤器逐段接收内部处理器的输出，经变换后写入 `out`. 输出结束后调用一次 [`TextFilter::finish`],
+/// 以便输出缓冲的内容或收尾。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{fmt_filtered, DisplayProxy, FmtTrait, FmtWith, TextFilter};
+/// struct Rot13;
+/// impl TextFilter for Rot13 {
+///     fn write_str(&mut self, out: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+///         use std::fmt::Write;
+///         for c in s.chars() {
+///             out.write_char(match c {
+///                 'a'..='m' | 'A'..='M' => (c as u8 + 13) as char,
+///                 'n'..='z' | 'N'..='Z' => (c as u8 - 13) as char,
+///                 _ => c,
+///             })?;
+///         }
+///         Ok(())
+///     }
+/// }
+/// let s = "Hello";
+/// let proxy = s.fmt_with(&|s, f| fmt_filtered(Rot13, s, DisplayProxy, f, FmtTrait::Display));
+/// assert_eq!(format!("{}", proxy), "Uryyb");
+/// ```
+pub trait TextFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result;
+    #[inline]
+    fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let _ = out;
+        Ok(())
+    }
+}
+
+impl<F: TextFilter + ?::core::marker::Sized> TextFilter for &mut F {
+    #[inline]
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        (**self).write_str(out, s)
+    }
+    #[inline]
+    fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        (**self).finish(out)
+    }
+}
+
+struct FilterWriter<'a, 'f, F> {
+    filter: F,
+    out: &'a mut ::core::fmt::Formatter<'f>,
+}
+
+impl<F: TextFilter> ::core::fmt::Write for FilterWriter<'_, '_, F> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.filter.write_str(self.out, s)
+    }
+}
+
+/// 将 `handler` 对 `data` 的格式化结果经 `filter` 过滤后写入 `f`.
+///
+/// 内部处理器经由 `fmt_trait` 调用，并沿用 `f` 的 `+`、`#`、`0` 标志与精度（见 [`FmtOptions`]）。
+/// 宽度与对齐既不传给内部处理器，也不作用于过滤后的输出，需要时由调用方填充。
+pub fn fmt_filtered<F, T, H>(
+    filter: F,
+    data: &T,
+    handler: H,
+    f: &mut ::core::fmt::Formatter<'_>,
+    fmt_trait: FmtTrait,
+) -> ::core::fmt::Result
+where
+    F: TextFilter,
+    T: ?::core::marker::Sized,
+    H: FmtHandler<T>,
+{
+    fmt_filtered_with(filter, data, handler, f, FmtOptions::of(f), fmt_trait)
+}
+
+/// 与 [`fmt_filtered`] 相同，但内部处理器以 `options` 而非 `f` 的选项调用。
+pub(crate) fn fmt_filtered_with<F, T, H>(
+    filter: F,
+    data: &T,
+    handler: H,
+    f: &mut ::core::fmt::Formatter<'_>,
+    options: FmtOptions,
+    fmt_trait: FmtTrait,
+) -> ::core::fmt::Result
+where
+    F: TextFilter,
+    T: ?::core::marker::Sized,
+    H: FmtHandler<T>,
+{
+    let mut writer = FilterWriter { filter, out: f };
+    options.write_by(&mut writer, data, handler, fmt_trait)?;
+    writer.filter.finish(writer.out)
+}
+
+/// 为以 [`fmt_filtered`] 实现的过滤类处理器实现 [`FmtHandler`], 并将格式化特型转发给内部处理器。
+/// 宽度与对齐作用于过滤后的输出。
+macro_rules! impl_filter_handler {
+    ($handler: ident, |$this: ident| $filter: expr, $inner: expr) => {
+        impl<T, Handler> crate::FmtHandler<T> for $handler<Handler>
+        where
+            T: ?::core::marker::Sized,
+            Handler: crate::FmtHandler<T>,
+        {
+            #[inline]
+            fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.fmt_trait(data, f, crate::FmtTrait::Display)
+            }
+            #[inline]
+            fn fmt_trait(
+                &self,
+                data: &T,
+                f: &mut ::core::fmt::Formatter<'_>,
+                fmt_trait: crate::FmtTrait,
+            ) -> ::core::fmt::Result {
+                let $this = self;
+                crate::pad_output(f, |f, options| {
+                    crate::fmt_filtered_with($filter, data, $inner, f, options, fmt_trait)
+                })
+            }
+        }
+    };
+}
+pub(crate) use impl_filter_handler;
+
+/// 逐字符扫描 `s`, 将无需转义的片段整段写入，需转义的字符交由 `escape` 输出。
+pub(crate) fn write_escaped(
+    out: &mut ::core::fmt::Formatter<'_>,
+    s: &str,
+    needs_escape: impl Fn(char) -> bool,
+    mut escape: impl FnMut(char, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+) -> ::core::fmt::Result {
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if needs_escape(c) {
+            out.write_str(&s[start..i])?;
+            escape(c, out)?;
+            start = i + c.len_utf8();
+        }
+    }
+    out.write_str(&s[start..])
+}
+
+/// 暂存尚未确定如何输出的字符（通常是空白），按游程存放，最多 `N` 段，不分配内存。
+///
+/// 游程段数超出 `N` 时，最早的一段将交由 `overflow` 提前输出。
+pub(crate) struct PendingRuns<const N: usize> {
+    runs: [(char, usize); N],
+    len: usize,
+}
+
+impl<const N: usize> PendingRuns<N> {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            runs: [('\0', 0); N],
+            len: 0,
+        }
+    }
+    pub(crate) fn push(
+        &mut self,
+        c: char,
+        overflow: impl FnOnce(char, usize) -> ::core::fmt::Result,
+    ) -> ::core::fmt::Result {
+        if let Some((last, count)) = self.runs[..self.len].last_mut()
+            && *last == c
+        {
+            *count += 1;
+            return Ok(());
+        }
+        if self.len == N {
+            let (first, count) = self.runs[0];
+            self.runs.copy_within(1.., 0);
+            self.len -= 1;
+            overflow(first, count)?;
+        }
+        self.runs[self.len] = (c, 1);
+        self.len += 1;
+        Ok(())
+    }
+    /// 按顺序取出全部游程。
+    pub(crate) fn drain(
+        &mut self,
+        mut f: impl FnMut(char, usize) -> ::core::fmt::Result,
+    ) -> ::core::fmt::Result {
+        let len = ::core::mem::take(&mut self.len);
+        self.runs[..len]
+            .iter()
+            .try_for_each(|&(c, count)| f(c, count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DisplayProxy, FmtWith};
+
+    #[test]
+    fn test_fmt_filtered() {
+        // 统计写入长度的过滤器
+        struct Upper<'a>(&'a mut usize);
+        impl TextFilter for Upper<'_> {
+            fn write_str(
+                &mut self,
+                out: &mut ::core::fmt::Formatter<'_>,
+                s: &str,
+            ) -> ::core::fmt::Result {
+                *self.0 += s.len();
+                write!(out, "{}", s.to_uppercase())
+            }
+            fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(out, "({})", self.0)
+            }
+        }
+
+        // 测试选项的转发
+        let n = 1.23456;
+        let proxy = n.fmt_with(&|n, f| {
+            let mut len = 0;
+            fmt_filtered(Upper(&mut len), n, DisplayProxy, f, FmtTrait::Display)
+        });
+        assert_eq!(format!("{}", proxy), "1.23456(7)");
+        assert_eq!(format!("{:+.2}", proxy), "+1.23(5)");
+        assert_eq!(format!("{:#.1}", proxy), "1.2(3)");
+        // 宽度、对齐与填充字符不转发，也不作用于输出
+        assert_eq!(format!("{:8.2}", proxy), "1.23(4)");
+        assert_eq!(format!("{:*>+8.1}", proxy), "+1.2(4)");
+        assert_eq!(format!("{:08.3}", proxy), "1.235(5)");
+    }
+}
@@ -0,0 +1,228 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{FmtHandler, FmtTrait};
+
+/// 以 `xxd` 风格输出字节数据的格式化处理器
+///
+/// 每行依次为偏移量列、分组的十六进制字节以及可打印 ASCII 字符栏（不可打印字节显示为 `.`），行与行之间以 `\n` 分隔。
+/// 通过 `{:X}` 格式化时，偏移量与字节均使用大写字母。
+///
+/// # 字段
+/// - `width`: 每行的字节数，为 `0` 时视作 `16`.
+/// - `group`: 每组的字节数，组间以空格分隔；为 `0` 时不分组。
+/// - `offset_base`: 加到偏移量列上的基址。
+/// - `ascii`: 是否输出 ASCII 字符栏。
+/// - `squeeze`: 是否像 `hexdump` 那样将连续的相同行折叠为一行 `*`.
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, FmtBy, HexDump};
+/// let data = b"Hello, world!\n";
+/// assert_eq!(
+///     format!("{}", data.fmt_as::<HexDump>()),
+///     "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a       Hello, world!."
+/// );
+///
+/// let zeros = [0u8; 32];
+/// let dump = HexDump { width: 8, group: 1, squeeze: true, ..HexDump::default() };
+/// assert_eq!(
+///     format!("{:X}", zeros.fmt_by(dump)),
+///     "00000000: 00 00 00 00 00 00 00 00  ........\n*\n00000020"
+/// );
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef), 如 `[u8]`、`[u8; N]`、`&[u8]`、`Vec<u8>` 等；
+/// - 不分配内存，可用于 `no_std` 环境。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexDump {
+    pub width: usize,
+    pub group: usize,
+    pub offset_base: usize,
+    pub ascii: bool,
+    pub squeeze: bool,
+}
+
+impl ::core::default::Default for HexDump {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            width: 16,
+            group: 2,
+            offset_base: 0,
+            ascii: true,
+            squeeze: false,
+        }
+    }
+}
+
+impl HexDump {
+    fn fmt_offset(
+        &self,
+        offset: usize,
+        upper: bool,
+        f: &mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result {
+        let offset = self.offset_base.wrapping_add(offset);
+        if upper {
+            write!(f, "{:08X}", offset)
+        } else {
+            write!(f, "{:08x}", offset)
+        }
+    }
+    fn fmt_line(
+        &self,
+        offset: usize,
+        line: &[u8],
+        width: usize,
+        upper: bool,
+        f: &mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        self.fmt_offset(offset, upper, f)?;
+        f.write_char(':')?;
+        // 不输出 ASCII 栏时，末行无需补齐。
+        let columns = if self.ascii { width } else { line.len() };
+        for i in 0..columns {
+            if i == 0 || (self.group != 0 && i % self.group == 0) {
+                f.write_char(' ')?;
+            }
+            match line.get(i) {
+                Some(byte) if upper => write!(f, "{:02X}", byte)?,
+                Some(byte) => write!(f, "{:02x}", byte)?,
+                None => f.write_str("  ")?,
+            }
+        }
+        if self.ascii {
+            f.write_str("  ")?;
+            for &byte in line {
+                f.write_char(if byte.is_ascii_graphic() || byte == b' ' {
+                    byte as char
+                } else {
+                    '.'
+                })?;
+            }
+        }
+        Ok(())
+    }
+    fn fmt_bytes(
+        &self,
+        data: &[u8],
+        upper: bool,
+        f: &mut ::core::fmt::Formatter<'_>,
+    ) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        let width = if self.width == 0 { 16 } else { self.width };
+        let mut previous: Option<&[u8]> = None;
+        let mut squeezed = false;
+        for (index, line) in data.chunks(width).enumerate() {
+            let offset = index * width;
+            if self.squeeze && previous == Some(line) {
+                if !squeezed {
+                    f.write_str("\n*")?;
+                    squeezed = true;
+                }
+                continue;
+            }
+            if offset != 0 {
+                f.write_char('\n')?;
+            }
+            self.fmt_line(offset, line, width, upper, f)?;
+            previous = Some(line);
+            squeezed = false;
+        }
+        if squeezed {
+            // 与 `hexdump` 一致，以末尾偏移量标明数据的总长度。
+            f.write_char('\n')?;
+            self.fmt_offset(data.len(), upper, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> FmtHandler<T> for HexDump
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_bytes(data.as_ref(), false, f)
+    }
+    #[inline]
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        self.fmt_bytes(data.as_ref(), fmt_trait == FmtTrait::UpperHex, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy};
+
+    #[test]
+    fn test_hex_dump() {
+        // 测试多行与末行补齐
+        let data: Vec<u8> = (0x41..0x55).collect();
+        assert_eq!(
+            format!("{}", data.fmt_as::<HexDump>()),
+            "00000000: 4142 4344 4546 4748 494a 4b4c 4d4e 4f50  ABCDEFGHIJKLMNOP\n\
+             00000010: 5152 5354                                QRST"
+        );
+
+        // 测试大写、基址与关闭 ASCII 栏
+        let dump = HexDump {
+            width: 4,
+            group: 0,
+            offset_base: 0xff0,
+            ascii: false,
+            squeeze: false,
+        };
+        assert_eq!(
+            format!("{:X}", [0xde, 0xad, 0xbe, 0xef, 0x0a].fmt_by(dump)),
+            "00000FF0: DEADBEEF\n00000FF4: 0A"
+        );
+
+        // 测试空数据
+        assert_eq!(format!("{}", b"".fmt_as::<HexDump>()), "");
+    }
+
+    #[test]
+    fn test_hex_dump_squeeze() {
+        let mut data = [0u8; 20];
+        data[19] = b'!';
+        let dump = HexDump {
+            width: 4,
+            group: 1,
+            squeeze: true,
+            ..HexDump::default()
+        };
+        assert_eq!(
+            format!("{}", data.fmt_by(dump)),
+            "00000000: 00 00 00 00  ....\n*\n00000010: 00 00 00 21  ...!"
+        );
+    }
+}
@@ -0,0 +1,223 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    DisplayProxy, FmtHandler, FmtTrait, PendingRuns, TextFilter, fmt_filtered_with,
+    is_invisible_format,
+};
+
+/// [`ShowInvisibles`] 显示空格与制表符的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ShowWhitespace {
+    /// 原样输出。
+    Hidden,
+    /// 仅显示行尾的空格与制表符。
+    #[default]
+    Trailing,
+    /// 显示所有空格与制表符。
+    All,
+}
+
+/// 将内部处理器输出中的不可见字符显示出来的格式化处理器
+///
+/// - 控制字符显示为 Unicode 控制图形符号，如 `␀`、`␛`、`␡`; 其中 `\n` 显示为 `␊` 后仍保留换行；
+/// - 空格显示为 `·`, 制表符显示为 `→`, 范围由 [`ShowWhitespace`] 决定；
+/// - 零宽、双向控制等不可见的格式字符及 C1 控制字符显示为 `<U+200B>` 形式。
+///
+/// 宽度与对齐作用于显示后的输出，填充的空格不会被显示为 `·`.
+///
+/// # 字段
+/// - `controls`: 是否显示控制字符，默认为 `true`.
+/// - `format`: 是否显示不可见的格式字符，默认为 `true`.
+/// - `whitespace`: 空格与制表符的显示方式，默认为 [`ShowWhitespace::Trailing`].
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{DisplayProxy, FmtAs, FmtBy, ShowInvisibles, ShowWhitespace};
+/// let s = "a b \t\r\nc\u{200b}\0";
+/// assert_eq!(format!("{}", s.fmt_as::<ShowInvisibles>()), "a b·→␍␊\nc<U+200B>␀");
+/// let all: ShowInvisibles = ShowInvisibles { whitespace: ShowWhitespace::All, ..ShowInvisibles::default() };
+/// assert_eq!(format!("{}", "a b".fmt_by(all)), "a·b");
+/// ```
+/// # 注意
+/// - 行尾空白需暂存至行末才能确定如何输出；暂存按游程进行，空格与制表符交替超过 16 段时，较早的部分将按非行尾空白输出。
+#[derive(Debug, Clone, Copy)]
+pub struct ShowInvisibles<Handler = DisplayProxy> {
+    pub controls: bool,
+    pub format: bool,
+    pub whitespace: ShowWhitespace,
+    pub handler: Handler,
+}
+
+impl<Handler: ::core::default::Default> ::core::default::Default for ShowInvisibles<Handler> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            controls: true,
+            format: true,
+            whitespace: ShowWhitespace::Trailing,
+            handler: Handler::default(),
+        }
+    }
+}
+
+struct InvisiblesFilter {
+    controls: bool,
+    format: bool,
+    whitespace: ShowWhitespace,
+    pending: PendingRuns<16>,
+}
+
+impl InvisiblesFilter {
+    fn write_whitespace(
+        out: &mut ::core::fmt::Formatter<'_>,
+        c: char,
+        count: usize,
+        visible: bool,
+    ) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        let c = match c {
+            ' ' if visible => '·',
+            '\t' if visible => '→',
+            _ => c,
+        };
+        (0..count).try_for_each(|_| out.write_char(c))
+    }
+    fn write_char(&mut self, out: &mut ::core::fmt::Formatter<'_>, c: char) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        if matches!(c, ' ' | '\t') {
+            return match self.whitespace {
+                ShowWhitespace::Hidden => out.write_char(c),
+                ShowWhitespace::All => Self::write_whitespace(out, c, 1, true),
+                ShowWhitespace::Trailing => self
+                    .pending
+                    .push(c, |c, count| Self::write_whitespace(out, c, count, false)),
+            };
+        }
+        // 行尾空白以 `\r` 或 `\n` 结束。
+        let trailing = matches!(c, '\r' | '\n');
+        self.pending
+            .drain(|c, count| Self::write_whitespace(out, c, count, trailing))?;
+        match c {
+            '\n' if self.controls => out.write_str("␊\n"),
+            '\u{0}'..='\u{1f}' if self.controls => {
+                out.write_char(char::from_u32(0x2400 + c as u32).unwrap_or(c))
+            }
+            '\u{7f}' if self.controls => out.write_char('␡'),
+            '\u{80}'..='\u{9f}' if self.controls => write!(out, "<U+{:04X}>", c as u32),
+            _ if self.format && is_invisible_format(c) => write!(out, "<U+{:04X}>", c as u32),
+            _ => out.write_char(c),
+        }
+    }
+}
+
+impl TextFilter for InvisiblesFilter {
+    #[inline]
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(out, c))
+    }
+    #[inline]
+    fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.pending
+            .drain(|c, count| Self::write_whitespace(out, c, count, true))
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for ShowInvisibles<Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        crate::pad_output(f, |f, options| {
+            let filter = InvisiblesFilter {
+                controls: self.controls,
+                format: self.format,
+                whitespace: self.whitespace,
+                pending: PendingRuns::new(),
+            };
+            fmt_filtered_with(filter, data, self.handler, f, options, fmt_trait)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DebugProxy, FmtAs, FmtBy};
+
+    #[test]
+    fn test_show_invisibles() {
+        // 测试行尾空白（包括输出末尾）
+        let s = "x  \ny \t z \t";
+        assert_eq!(
+            format!("{}", s.fmt_as::<ShowInvisibles>()),
+            "x··␊\ny \t z·→"
+        );
+
+        // 测试关闭各类显示
+        let none = ShowInvisibles {
+            controls: false,
+            format: false,
+            whitespace: ShowWhitespace::Hidden,
+            handler: DisplayProxy,
+        };
+        assert_eq!(format!("{}", s.fmt_by(none)), s);
+
+        // 测试 C1 控制字符、DEL 与 Debug 输出的组合
+        let s = "\u{85}\u{7f}\u{feff}";
+        assert_eq!(
+            format!("{}", s.fmt_as::<ShowInvisibles>()),
+            "<U+0085>␡<U+FEFF>"
+        );
+        let debug = ShowInvisibles {
+            handler: DebugProxy,
+            ..ShowInvisibles::default()
+        };
+        assert_eq!(format!("{}", "a ".fmt_by(debug)), "\"a \"");
+
+        // 测试宽度按显示后的字符测量，且填充不被标记
+        assert_eq!(
+            format!("[{:>6}]", "a\u{200b}".fmt_as::<ShowInvisibles>()),
+            "[a<U+200B>]"
+        );
+        assert_eq!(
+            format!("[{:>4}]", "a ".fmt_as::<ShowInvisibles>()),
+            "[  a·]"
+        );
+        let all: ShowInvisibles = ShowInvisibles {
+            whitespace: ShowWhitespace::All,
+            ..ShowInvisibles::default()
+        };
+        assert_eq!(format!("[{:<5}]", "a b".fmt_by(all)), "[a·b  ]");
+    }
+}
@@ -39,6 +39,7 @@ use crate::FmtHandler;
 /// # 注意
 /// - 要求 &Iter 实现 [`IntoIterator`];
 /// - 对于 &Iter 没有实现 [`IntoIterator`] 的类型（如 [`Range`](::core::ops::Range)），请使用 [`CloneIterJoined`], 它要求 Iter 实现 [`Clone`].
+/// - 输出总在一行；需要在超出宽度时逐行排版，请使用 [`PrettyList`](crate::PrettyList).
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Joined<Delim>(pub Delim)
 where
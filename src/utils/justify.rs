@@ -0,0 +1,331 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DisplayProxy, TextFilter, Wrap, impl_filter_handler, str_width};
+
+/// 行内对齐方式，见 [`Justify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineAlign {
+    #[default]
+    Left,
+    Right,
+    Center,
+    /// 两端对齐：在单词间插入空格，使行宽恰为目标宽度；段落的最后一行保持左对齐。
+    Justify,
+}
+
+/// 未启用 `alloc` 特性时行缓冲区的大小（字节）。
+#[cfg(not(feature = "alloc"))]
+const LINE_CAPACITY: usize = 1024;
+
+/// 暂存一行输出，以便测量其宽度后再对齐。
+struct LineBuffer {
+    #[cfg(feature = "alloc")]
+    buf: ::alloc::string::String,
+    #[cfg(not(feature = "alloc"))]
+    buf: [u8; LINE_CAPACITY],
+    #[cfg(not(feature = "alloc"))]
+    len: usize,
+}
+
+impl LineBuffer {
+    #[inline]
+    fn new() -> Self {
+        Self {
+            #[cfg(feature = "alloc")]
+            buf: ::alloc::string::String::new(),
+            #[cfg(not(feature = "alloc"))]
+            buf: [0; LINE_CAPACITY],
+            #[cfg(not(feature = "alloc"))]
+            len: 0,
+        }
+    }
+    /// 追加一个字符，缓冲区已满时返回 `false`.
+    #[inline]
+    fn push(&mut self, c: char) -> bool {
+        #[cfg(feature = "alloc")]
+        {
+            self.buf.push(c);
+            true
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            if self.len + c.len_utf8() > LINE_CAPACITY {
+                return false;
+            }
+            c.encode_utf8(&mut self.buf[self.len..]);
+            self.len += c.len_utf8();
+            true
+        }
+    }
+    #[inline]
+    fn as_str(&self) -> &str {
+        #[cfg(feature = "alloc")]
+        {
+            &self.buf
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            // 缓冲区中只有完整的字符。
+            ::core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+        }
+    }
+    #[inline]
+    fn clear(&mut self) {
+        #[cfg(feature = "alloc")]
+        self.buf.clear();
+        #[cfg(not(feature = "alloc"))]
+        {
+            self.len = 0;
+        }
+    }
+}
+
+fn write_spaces(out: &mut ::core::fmt::Formatter<'_>, n: usize) -> ::core::fmt::Result {
+    use ::core::fmt::Write;
+    (0..n).try_for_each(|_| out.write_char(' '))
+}
+
+/// 按 `align` 将一行补足至 `width` 列。`last` 表示该行为段落的最后一行。
+fn write_aligned(
+    out: &mut ::core::fmt::Formatter<'_>,
+    line: &str,
+    width: usize,
+    align: LineAlign,
+    last: bool,
+) -> ::core::fmt::Result {
+    let extra = width.saturating_sub(str_width(line));
+    match align {
+        _ if line.is_empty() => {}
+        LineAlign::Right => write_spaces(out, extra)?,
+        LineAlign::Center => write_spaces(out, extra / 2)?,
+        LineAlign::Justify if !last && extra != 0 => {
+            // 行首缩进不参与分配。
+            let body_start = line.len() - line.trim_start_matches(' ').len();
+            let body = &line[body_start..];
+            let gaps = body
+                .char_indices()
+                .filter(|&(i, c)| c != ' ' && i != 0 && body.as_bytes()[i - 1] == b' ')
+                .count();
+            if let Some(each) = extra.checked_div(gaps) {
+                out.write_str(&line[..body_start])?;
+                let rest = extra % gaps;
+                let (mut start, mut gap) = (0, 0);
+                for (i, c) in body.char_indices() {
+                    if c != ' ' && i != 0 && body.as_bytes()[i - 1] == b' ' {
+                        out.write_str(&body[start..i])?;
+                        write_spaces(out, each + (gap < rest) as usize)?;
+                        start = i;
+                        gap += 1;
+                    }
+                }
+                return out.write_str(&body[start..]);
+            }
+        }
+        _ => {}
+    }
+    out.write_str(line)
+}
+
+struct JustifyFilter {
+    width: usize,
+    align: LineAlign,
+    line: LineBuffer,
+    /// 当前行超出缓冲区，已原样写出。
+    overflowed: bool,
+    /// 已遇到行尾的换行符，尚需根据下一行是否为空判断该行是否为段落的最后一行。
+    pending: bool,
+}
+
+impl JustifyFilter {
+    fn end_line(
+        &mut self,
+        out: &mut ::core::fmt::Formatter<'_>,
+        last: bool,
+    ) -> ::core::fmt::Result {
+        if !::core::mem::take(&mut self.overflowed) {
+            write_aligned(out, self.line.as_str(), self.width, self.align, last)?;
+        }
+        self.line.clear();
+        Ok(())
+    }
+}
+
+impl TextFilter for JustifyFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        for c in s.chars() {
+            if ::core::mem::take(&mut self.pending) {
+                self.end_line(out, c == '\n')?;
+                out.write_char('\n')?;
+            }
+            if c == '\n' {
+                self.pending = true;
+            } else if self.overflowed {
+                out.write_char(c)?;
+            } else if !self.line.push(c) {
+                // 行过长，放弃对齐，原样输出。
+                out.write_str(self.line.as_str())?;
+                out.write_char(c)?;
+                self.line.clear();
+                self.overflowed = true;
+            }
+        }
+        Ok(())
+    }
+    fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.end_line(out, true)?;
+        if self.pending {
+            out.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+/// 将内部处理器的输出断行后，按行对齐至固定宽度的格式化处理器
+///
+/// 先以 [`Wrap`] 按显示宽度断行，再按 `align` 对齐每一行：右对齐与居中在行首补空格，
+/// 两端对齐则将多余的列均分到单词间的空白中（靠左的空白多分一列）。以空行分隔的段落的最后一行，
+/// 以及没有单词间空白的行（如纯中日韩文字），在两端对齐时保持左对齐。宽度按 [`str_width`] 计算。
+///
+/// # 字段
+/// - `width`: 目标行宽。
+/// - `align`: 对齐方式，默认为两端对齐。
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, Justify, LineAlign};
+/// let text = "The quick brown fox jumps over the lazy dog.\n\nThe end.";
+/// assert_eq!(
+///     format!("{}", text.fmt_by(Justify::new(16))),
+///     "The  quick brown\nfox  jumps  over\nthe lazy dog.\n\nThe end."
+/// );
+/// let centered = Justify::new(16).with_align(LineAlign::Center);
+/// assert_eq!(format!("{}", "中文 居中".fmt_by(centered)), "   中文 居中");
+/// ```
+/// # 注意
+/// - 行尾不补空格；需要定宽的矩形输出时，可在外层使用 [`Padded`](crate::Padded).
+/// - 未启用 `alloc` 特性时，每行暂存于 1024 字节的栈上缓冲区，不分配内存，超出的行原样输出而不对齐；
+///   启用后行缓冲区位于堆上，没有此限制。
+#[derive(Debug, Clone, Copy)]
+pub struct Justify<Handler = DisplayProxy> {
+    pub width: usize,
+    pub align: LineAlign,
+    pub handler: Handler,
+}
+
+impl Justify {
+    /// 以两端对齐、[`DisplayProxy`] 为内部处理器构造。
+    #[inline]
+    pub const fn new(width: usize) -> Self {
+        Self {
+            width,
+            align: LineAlign::Justify,
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl<Handler> Justify<Handler> {
+    /// 替换对齐方式。
+    #[inline]
+    pub fn with_align(self, align: LineAlign) -> Self {
+        Self { align, ..self }
+    }
+    /// 替换内部处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> Justify<NewHandler> {
+        Justify {
+            width: self.width,
+            align: self.align,
+            handler,
+        }
+    }
+}
+
+impl_filter_handler!(
+    Justify,
+    |this| JustifyFilter {
+        width: this.width,
+        align: this.align,
+        line: LineBuffer::new(),
+        overflowed: false,
+        pending: false,
+    },
+    Wrap::new(this.width).with_handler(this.handler)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtBy, Joined};
+
+    #[test]
+    fn test_justify() {
+        let text = "Rust is a language empowering everyone to build reliable software.";
+        let justified = format!("{}", text.fmt_by(Justify::new(20)));
+        assert_eq!(
+            justified,
+            "Rust  is  a language\nempowering  everyone\nto   build  reliable\nsoftware."
+        );
+        assert!(justified.lines().rev().skip(1).all(|line| line.len() == 20));
+
+        // 测试各对齐方式与段落
+        let text = "ab cd\n\nef";
+        let align = |align| format!("{}", text.fmt_by(Justify::new(7).with_align(align)));
+        assert_eq!(align(LineAlign::Left), "ab cd\n\nef");
+        assert_eq!(align(LineAlign::Right), "  ab cd\n\n     ef");
+        assert_eq!(align(LineAlign::Center), " ab cd\n\n  ef");
+        assert_eq!(align(LineAlign::Justify), "ab cd\n\nef");
+
+        // 测试宽字符与保留末尾换行
+        let text = "中文 与 English 混排\n";
+        assert_eq!(
+            format!("{}", text.fmt_by(Justify::new(12))),
+            "中文      与\nEnglish 混排\n"
+        );
+
+        // 测试与 Joined 组合
+        let words = ["alpha", "beta", "gamma", "delta", "epsilon"];
+        let proxy = words.fmt_by(Justify::new(12).with_handler(Joined(" ")));
+        assert_eq!(format!("{}", proxy), "alpha   beta\ngamma  delta\nepsilon");
+    }
+
+    #[test]
+    fn test_justify_long_line() {
+        // 无法断开的超长行原样输出
+        let long = "x".repeat(2000);
+        let text = format!("a b\n{}\nc d", long);
+        let justify = Justify {
+            width: 4000,
+            align: LineAlign::Right,
+            handler: DisplayProxy,
+        };
+        let output = format!("{}", text.fmt_by(justify));
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].trim_start(), "a b");
+        assert!(lines[1].ends_with(&long));
+        assert_eq!(lines[2].len(), 4000);
+    }
+}
@@ -0,0 +1,216 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DisplayProxy, FmtHandler, FmtTrait, LineStats, TextFilter, fmt_filtered};
+
+struct LineNumberFilter<Sep> {
+    number: usize,
+    width: usize,
+    separator: Sep,
+    highlight: Option<(usize, usize)>,
+    at_line_start: bool,
+}
+
+impl<Sep: ::core::fmt::Display> TextFilter for LineNumberFilter<Sep> {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        for line in s.split_inclusive('\n') {
+            if self.at_line_start {
+                if let Some((first, last)) = self.highlight {
+                    let marked = (first..=last).contains(&self.number);
+                    out.write_str(if marked { "> " } else { "  " })?;
+                }
+                write!(out, "{:>2$}{}", self.number, self.separator, self.width)?;
+            }
+            out.write_str(line)?;
+            self.at_line_start = line.ends_with('\n');
+            self.number += self.at_line_start as usize;
+        }
+        Ok(())
+    }
+}
+
+/// 为内部处理器输出的每一行添加行号的格式化处理器
+///
+/// 行号右对齐，其后接分隔符。未指定宽度时，先统计行数，以最大行号的位数为宽度。
+/// 可标记一段行号范围：此时每行前多出两列，范围内的行以 `> ` 标记。
+///
+/// # 字段
+/// - `start`: 第一行的行号，默认为 `1`.
+/// - `width`: 行号的宽度，为 [`None`] 时自动计算。
+/// - `separator`: 行号与内容之间的分隔符，默认为 `" │ "`.
+/// - `highlight`: 需标记的行号范围（首尾均包含），为 [`None`] 时不标记。
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, LineNumbers, Wrap};
+/// let config = "[server]\nport = 8080\n";
+/// let numbered = LineNumbers {
+///     start: 9,
+///     ..LineNumbers::new()
+/// };
+/// assert_eq!(
+///     format!("{}", config.fmt_by(numbered.with_highlight(10, 10))),
+///     "   9 │ [server]\n> 10 │ port = 8080\n"
+/// );
+/// // 与其他处理器组合
+/// let text = "a long line that wraps";
+/// let numbered = LineNumbers::new().with_separator(": ").with_handler(Wrap::new(10));
+/// assert_eq!(format!("{}", text.fmt_by(numbered)), "1: a long\n2: line that\n3: wraps");
+/// ```
+/// # 注意
+/// - 自动计算宽度时，内部处理器会被调用两次，不分配内存。
+/// - 输出末尾的换行符之后不再添加行号；空输出不产生任何内容。
+#[derive(Debug, Clone, Copy)]
+pub struct LineNumbers<Sep = &'static str, Handler = DisplayProxy> {
+    pub start: usize,
+    pub width: Option<usize>,
+    pub separator: Sep,
+    pub highlight: Option<(usize, usize)>,
+    pub handler: Handler,
+}
+
+impl LineNumbers {
+    /// 自 `1` 起编号、自动计算宽度、以 `" │ "` 分隔，以 [`DisplayProxy`] 为内部处理器构造。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            start: 1,
+            width: None,
+            separator: " │ ",
+            highlight: None,
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl ::core::default::Default for LineNumbers {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Sep, Handler> LineNumbers<Sep, Handler> {
+    /// 标记行号 `first` 至 `last` (包含）的行。
+    #[inline]
+    pub fn with_highlight(self, first: usize, last: usize) -> Self {
+        Self {
+            highlight: Some((first, last)),
+            ..self
+        }
+    }
+    /// 替换分隔符。
+    #[inline]
+    pub fn with_separator<NewSep>(self, separator: NewSep) -> LineNumbers<NewSep, Handler> {
+        LineNumbers {
+            start: self.start,
+            width: self.width,
+            separator,
+            highlight: self.highlight,
+            handler: self.handler,
+        }
+    }
+    /// 替换内部处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> LineNumbers<Sep, NewHandler> {
+        LineNumbers {
+            start: self.start,
+            width: self.width,
+            separator: self.separator,
+            highlight: self.highlight,
+            handler,
+        }
+    }
+}
+
+impl<T, Sep, Handler> FmtHandler<T> for LineNumbers<Sep, Handler>
+where
+    T: ?::core::marker::Sized,
+    Sep: ::core::fmt::Display + ::core::marker::Copy,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        let width = match self.width {
+            Some(width) => width,
+            None => {
+                let mut stats = LineStats::default();
+                fmt_filtered(&mut stats, data, self.handler, f, fmt_trait)?;
+                let last = self.start + stats.lines().saturating_sub(1);
+                last.checked_ilog10().unwrap_or(0) as usize + 1
+            }
+        };
+        let filter = LineNumberFilter {
+            number: self.start,
+            width,
+            separator: self.separator,
+            highlight: self.highlight,
+            at_line_start: true,
+        };
+        fmt_filtered(filter, data, self.handler, f, fmt_trait)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy, Joined};
+
+    #[test]
+    fn test_line_numbers() {
+        // 测试自动宽度
+        let lines: Vec<_> = (1..=10).map(|i| i * i).collect();
+        let numbered = LineNumbers::new()
+            .with_separator(' ')
+            .with_handler(Joined('\n'));
+        let output = format!("{}", lines.fmt_by(numbered));
+        assert!(output.starts_with(" 1 1\n 2 4\n"));
+        assert!(output.ends_with("\n10 100"));
+
+        // 测试固定宽度、空行与空输出
+        let numbered = LineNumbers {
+            width: Some(3),
+            ..LineNumbers::new()
+        };
+        assert_eq!(
+            format!("{}", "a\n\nb".fmt_by(numbered)),
+            "  1 │ a\n  2 │ \n  3 │ b"
+        );
+        assert_eq!(format!("{}", "".fmt_as::<LineNumbers>()), "");
+
+        // 测试标记范围
+        let numbered = LineNumbers::new().with_highlight(2, 3).with_separator("|");
+        assert_eq!(
+            format!("{}", "a\nb\nc\nd".fmt_by(numbered)),
+            "  1|a\n> 2|b\n> 3|c\n  4|d"
+        );
+    }
+}
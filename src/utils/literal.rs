@@ -0,0 +1,433 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::FmtHandler;
+
+/// 是否为零宽或双向控制等不可见的格式字符。
+#[inline]
+pub(crate) const fn is_invisible_format(c: char) -> bool {
+    matches!(
+        c,
+        '\u{ad}'
+            | '\u{61c}'
+            | '\u{180e}'
+            | '\u{200b}'..='\u{200f}'
+            | '\u{2028}'..='\u{202e}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{206f}'
+            | '\u{feff}'
+    )
+}
+
+/// 将字符串输出为 Rust 字符串字面量的格式化处理器
+///
+/// 转义规则固定，不随 Rust 版本变化：
+/// - `\`、`"` 及 `\n`、`\r`、`\t`、`\0` 使用简写；
+/// - 其余控制字符与零宽、双向控制等不可见字符使用 `\u{..}`;
+/// - 其余字符原样输出。
+///
+/// `raw` 为 `true` 时，若内容无需上述简写以外的转义，且原始字符串字面量（`r"..."`、`r#"..."#` 等）更短，则输出后者。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, FmtBy, RustStr};
+/// let s = "say \"你好\"\n\u{200b}";
+/// assert_eq!(format!("{}", s.fmt_as::<RustStr>()), r#""say \"你好\"\n\u{200b}""#);
+///
+/// let path = r#"C:\"a"\b"#;
+/// assert_eq!(format!("{}", path.fmt_as::<RustStr>()), r#""C:\\\"a\"\\b""#);
+/// assert_eq!(format!("{}", path.fmt_by(RustStr { raw: true })), r##"r#"C:\"a"\b"#"##);
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<str>`](::core::convert::AsRef).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RustStr {
+    pub raw: bool,
+}
+
+impl RustStr {
+    fn escape(c: char) -> Option<&'static str> {
+        match c {
+            '\\' => Some("\\\\"),
+            '"' => Some("\\\""),
+            '\n' => Some("\\n"),
+            '\r' => Some("\\r"),
+            '\t' => Some("\\t"),
+            '\0' => Some("\\0"),
+            _ => None,
+        }
+    }
+    #[inline]
+    fn needs_unicode_escape(c: char) -> bool {
+        c.is_control() && Self::escape(c).is_none() || is_invisible_format(c)
+    }
+    /// 若可用原始字符串字面量且其更短，返回所需的 `#` 数量。
+    fn raw_hashes(s: &str) -> Option<usize> {
+        let mut escaped_len = 2;
+        let mut hashes = 0;
+        let mut run: Option<usize> = None;
+        for c in s.chars() {
+            if matches!(c, '\\' | '"') {
+                escaped_len += 2;
+            } else if Self::escape(c).is_some() || Self::needs_unicode_escape(c) {
+                return None;
+            } else {
+                escaped_len += c.len_utf8();
+            }
+            run = match (c, run) {
+                ('"', _) => Some(0),
+                ('#', Some(n)) => Some(n + 1),
+                _ => None,
+            };
+            if let Some(n) = run {
+                hashes = hashes.max(n + 1);
+            }
+        }
+        (s.len() + 3 + 2 * hashes < escaped_len).then_some(hashes)
+    }
+}
+
+impl<T> FmtHandler<T> for RustStr
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<str>,
+{
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let s = data.as_ref();
+        if self.raw
+            && let Some(hashes) = Self::raw_hashes(s)
+        {
+            f.write_str("r")?;
+            (0..hashes).try_for_each(|_| f.write_str("#"))?;
+            write!(f, "\"{}\"", s)?;
+            return (0..hashes).try_for_each(|_| f.write_str("#"));
+        }
+        f.write_str("\"")?;
+        crate::write_escaped(
+            f,
+            s,
+            |c| Self::escape(c).is_some() || Self::needs_unicode_escape(c),
+            |c, f| match Self::escape(c) {
+                Some(e) => f.write_str(e),
+                None => write!(f, "\\u{{{:x}}}", c as u32),
+            },
+        )?;
+        f.write_str("\"")
+    }
+}
+
+/// 逐字节扫描 `bytes`, 将无需转义的片段整段写入，需转义的字节交由 `escape` 输出。
+///
+/// 非可打印 ASCII 的字节总是交由 `escape` 输出，因而整段写入的片段必为合法 UTF-8.
+fn write_escaped_bytes(
+    f: &mut ::core::fmt::Formatter<'_>,
+    bytes: &[u8],
+    needs_escape: impl Fn(usize, u8) -> bool,
+    mut escape: impl FnMut(u8, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+) -> ::core::fmt::Result {
+    let write_run = |f: &mut ::core::fmt::Formatter<'_>, run: &[u8]| {
+        f.write_str(::core::str::from_utf8(run).map_err(|_| ::core::fmt::Error)?)
+    };
+    let mut start = 0;
+    for (i, &b) in bytes.iter().enumerate() {
+        if !(b' '..=b'~').contains(&b) || needs_escape(i, b) {
+            write_run(f, &bytes[start..i])?;
+            escape(b, f)?;
+            start = i + 1;
+        }
+    }
+    write_run(f, &bytes[start..])
+}
+
+/// 将字节数据输出为 Rust 字节串字面量的格式化处理器
+///
+/// 可打印 ASCII 字符原样输出；`\`、`"` 及 `\n`、`\r`、`\t`、`\0` 使用简写；其余字节使用 `\xNN`.
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, RustBytes};
+/// let data = b"GET /\r\n\xff\0";
+/// assert_eq!(format!("{}", data.fmt_as::<RustBytes>()), r#"b"GET /\r\n\xff\0""#);
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RustBytes;
+
+impl<T> FmtHandler<T> for RustBytes
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+{
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str("b\"")?;
+        write_escaped_bytes(
+            f,
+            data.as_ref(),
+            |_, b| matches!(b, b'\\' | b'"'),
+            |b, f| match b {
+                b'\\' => f.write_str("\\\\"),
+                b'"' => f.write_str("\\\""),
+                b'\n' => f.write_str("\\n"),
+                b'\r' => f.write_str("\\r"),
+                b'\t' => f.write_str("\\t"),
+                b'\0' => f.write_str("\\0"),
+                _ => write!(f, "\\x{:02x}", b),
+            },
+        )?;
+        f.write_str("\"")
+    }
+}
+
+/// 将字节数据输出为 C 字符串字面量的格式化处理器
+///
+/// - `\\`、`"` 及 `\a`、`\b`、`\f`、`\n`、`\r`、`\t`、`\v` 使用简写；
+/// - 紧跟在 `?` 之后的 `?` 写作 `\?`, 以免构成三连符；
+/// - 其余非可打印 ASCII 的字节（包括 UTF-8 多字节序列）使用三位八进制转义 `\ooo`,
+///   它不会像 `\x` 那样吞并其后的十六进制数字。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{CStrLit, FmtAs};
+/// assert_eq!(format!("{}", "what??!\n".fmt_as::<CStrLit>()), r#""what?\?!\n""#);
+/// assert_eq!(format!("{}", "é1".fmt_as::<CStrLit>()), r#""\303\2511""#);
+/// ```
+/// # 注意
+/// - 数据类型需实现 [`AsRef<[u8]>`](::core::convert::AsRef);
+/// - 不输出结尾的 `\0`, 这由 C 编译器补充。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CStrLit;
+
+impl<T> FmtHandler<T> for CStrLit
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<[u8]>,
+{
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let bytes = data.as_ref();
+        f.write_str("\"")?;
+        write_escaped_bytes(
+            f,
+            bytes,
+            |i, b| matches!(b, b'\\' | b'"') || b == b'?' && i > 0 && bytes[i - 1] == b'?',
+            |b, f| match b {
+                b'\\' => f.write_str("\\\\"),
+                b'"' => f.write_str("\\\""),
+                b'?' => f.write_str("\\?"),
+                0x07 => f.write_str("\\a"),
+                0x08 => f.write_str("\\b"),
+                0x0c => f.write_str("\\f"),
+                b'\n' => f.write_str("\\n"),
+                b'\r' => f.write_str("\\r"),
+                b'\t' => f.write_str("\\t"),
+                0x0b => f.write_str("\\v"),
+                _ => write!(f, "\\{:03o}", b),
+            },
+        )?;
+        f.write_str("\"")
+    }
+}
+
+/// 是否为 Python `str.isprintable()` 视为不可打印的字符：控制、格式、私用与非字符码位，以及空格以外的分隔符。
+///
+/// 未分配的码位无法在不携带 Unicode 数据表的情况下识别，视为可打印。
+fn is_py_unprintable(c: char) -> bool {
+    c.is_control()
+        || is_invisible_format(c)
+        || matches!(
+            c,
+            '\u{a0}'
+                | '\u{600}'..='\u{605}'
+                | '\u{6dd}'
+                | '\u{70f}'
+                | '\u{890}'..='\u{891}'
+                | '\u{8e2}'
+                | '\u{1680}'
+                | '\u{2000}'..='\u{200a}'
+                | '\u{202f}'
+                | '\u{205f}'
+                | '\u{3000}'
+                | '\u{e000}'..='\u{f8ff}'
+                | '\u{fdd0}'..='\u{fdef}'
+                | '\u{fff9}'..='\u{fffb}'
+                | '\u{110bd}'
+                | '\u{110cd}'
+                | '\u{13430}'..='\u{1343f}'
+                | '\u{1bca0}'..='\u{1bca3}'
+                | '\u{1d173}'..='\u{1d17a}'
+                | '\u{e0001}'
+                | '\u{e0020}'..='\u{e007f}'
+                | '\u{f0000}'..='\u{10ffff}'
+        )
+        || c as u32 & 0xfffe == 0xfffe
+}
+
+/// 将字符串或字节数据输出为 Python `repr()` 形式的格式化处理器
+///
+/// 与 Python 相同：内容含 `'` 而不含 `"` 时以 `"` 包围，否则以 `'` 包围；字节数据带有 `b` 前缀。
+/// - `\\`、所选引号及 `\n`、`\r`、`\t` 使用简写；
+/// - 字符串中其余 `str.isprintable()` 为假的字符（控制、格式、私用字符及空格以外的分隔符）按码位大小
+///   使用 `\xNN`、`\uNNNN` 或 `\UNNNNNNNN`, 其余字符原样输出；
+/// - 字节数据中非可打印 ASCII 的字节使用 `\xNN`.
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, PyRepr};
+/// assert_eq!(format!("{}", "it's\t世界".fmt_as::<PyRepr>()), r#""it's\t世界""#);
+/// assert_eq!(format!("{}", b"\x00'\"".fmt_as::<PyRepr>()), r#"b'\x00\'"'"#);
+/// ```
+/// # 注意
+/// - 支持的数据类型为 `str`、`&str`、`[u8]`、`&[u8]` 与 `[u8; N]`.
+/// - 不携带 Unicode 数据表，未分配的码位原样输出，而 Python 会将其转义；对已分配的字符与 Python 一致。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PyRepr;
+
+impl PyRepr {
+    #[inline]
+    fn quote(has_single: bool, has_double: bool) -> char {
+        if has_single && !has_double { '"' } else { '\'' }
+    }
+    fn fmt_str(s: &str, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        let quote = Self::quote(s.contains('\''), s.contains('"'));
+        f.write_char(quote)?;
+        crate::write_escaped(
+            f,
+            s,
+            |c| c == '\\' || c == quote || is_py_unprintable(c),
+            |c, f| match c {
+                '\\' => f.write_str("\\\\"),
+                '\n' => f.write_str("\\n"),
+                '\r' => f.write_str("\\r"),
+                '\t' => f.write_str("\\t"),
+                _ if c == quote => write!(f, "\\{}", c),
+                _ if (c as u32) < 0x100 => write!(f, "\\x{:02x}", c as u32),
+                _ if (c as u32) < 0x10000 => write!(f, "\\u{:04x}", c as u32),
+                _ => write!(f, "\\U{:08x}", c as u32),
+            },
+        )?;
+        f.write_char(quote)
+    }
+    fn fmt_bytes(bytes: &[u8], f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        let quote = Self::quote(bytes.contains(&b'\''), bytes.contains(&b'"')) as u8;
+        f.write_char('b')?;
+        f.write_char(quote as char)?;
+        write_escaped_bytes(
+            f,
+            bytes,
+            |_, b| b == b'\\' || b == quote,
+            |b, f| match b {
+                b'\\' => f.write_str("\\\\"),
+                b'\n' => f.write_str("\\n"),
+                b'\r' => f.write_str("\\r"),
+                b'\t' => f.write_str("\\t"),
+                _ if b == quote => write!(f, "\\{}", b as char),
+                _ => write!(f, "\\x{:02x}", b),
+            },
+        )?;
+        f.write_char(quote as char)
+    }
+}
+
+impl FmtHandler<str> for PyRepr {
+    #[inline]
+    fn fmt(&self, data: &str, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        Self::fmt_str(data, f)
+    }
+}
+impl FmtHandler<&str> for PyRepr {
+    #[inline(always)]
+    fn fmt(&self, data: &&str, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        Self::fmt_str(data, f)
+    }
+}
+impl FmtHandler<[u8]> for PyRepr {
+    #[inline]
+    fn fmt(&self, data: &[u8], f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        Self::fmt_bytes(data, f)
+    }
+}
+impl FmtHandler<&[u8]> for PyRepr {
+    #[inline(always)]
+    fn fmt(&self, data: &&[u8], f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        Self::fmt_bytes(data, f)
+    }
+}
+impl<const N: usize> FmtHandler<[u8; N]> for PyRepr {
+    #[inline(always)]
+    fn fmt(&self, data: &[u8; N], f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        Self::fmt_bytes(data, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy};
+
+    #[test]
+    fn test_rust_literals() {
+        // 测试 Unicode 转义
+        let s = "\u{1}\u{7f}\u{202e}é";
+        assert_eq!(
+            format!("{}", s.fmt_as::<RustStr>()),
+            r#""\u{1}\u{7f}\u{202e}é""#
+        );
+
+        // 测试原始字符串的选择
+        let raw = RustStr { raw: true };
+        assert_eq!(format!("{}", "plain".fmt_by(raw)), r#""plain""#);
+        assert_eq!(format!("{}", r"\d+\.\d+".fmt_by(raw)), r#"r"\d+\.\d+""#);
+        assert_eq!(
+            format!("{}", r##"a"#\\\\\\"##.fmt_by(raw)),
+            r###"r##"a"#\\\\\\"##"###
+        );
+        // 含换行时不使用原始字符串
+        assert_eq!(format!("{}", "\\\\\n".fmt_by(raw)), r#""\\\\\n""#);
+
+        assert_eq!(format!("{}", b"".fmt_as::<RustBytes>()), r#"b"""#);
+        assert_eq!(
+            format!("{}", [b'"', 0x7f, b'\\'].fmt_as::<RustBytes>()),
+            r#"b"\"\x7f\\""#
+        );
+    }
+
+    #[test]
+    fn test_c_and_python_literals() {
+        assert_eq!(format!("{}", "??=?a?".fmt_as::<CStrLit>()), r#""?\?=?a?""#);
+        assert_eq!(
+            format!("{}", b"\x07\x0b\x1b9".fmt_as::<CStrLit>()),
+            r#""\a\v\0339""#
+        );
+
+        assert_eq!(format!("{}", "".fmt_as::<PyRepr>()), "''");
+        assert_eq!(format!("{}", "'\"".fmt_as::<PyRepr>()), r#"'\'"'"#);
+        assert_eq!(
+            format!("{}", "\u{1}\u{85}\u{200b}".fmt_as::<PyRepr>()),
+            r"'\x01\x85\u200b'"
+        );
+        assert_eq!(
+            format!("{}", "a\u{a0}\u{3000}\u{e000}\u{e0001}é".fmt_as::<PyRepr>()),
+            r"'a\xa0\u3000\ue000\U000e0001é'"
+        );
+        let bytes: &[u8] = b"caf\xc3\xa9";
+        assert_eq!(format!("{}", bytes.fmt_as::<PyRepr>()), r"b'caf\xc3\xa9'");
+    }
+}
@@ -24,14 +24,14 @@
 #[macro_export]
 macro_rules! all_fmt_impl {
     ($macro_name: ident) => {
-        $macro_name!(::core::fmt::Debug);
-        $macro_name!(::core::fmt::Display);
-        $macro_name!(::core::fmt::Binary);
-        $macro_name!(::core::fmt::LowerExp);
-        $macro_name!(::core::fmt::UpperExp);
-        $macro_name!(::core::fmt::LowerHex);
-        $macro_name!(::core::fmt::UpperHex);
-        $macro_name!(::core::fmt::Octal);
-        $macro_name!(::core::fmt::Pointer);
+        $macro_name!(::core::fmt::Debug, Debug);
+        $macro_name!(::core::fmt::Display, Display);
+        $macro_name!(::core::fmt::Binary, Binary);
+        $macro_name!(::core::fmt::LowerExp, LowerExp);
+        $macro_name!(::core::fmt::UpperExp, UpperExp);
+        $macro_name!(::core::fmt::LowerHex, LowerHex);
+        $macro_name!(::core::fmt::UpperHex, UpperHex);
+        $macro_name!(::core::fmt::Octal, Octal);
+        $macro_name!(::core::fmt::Pointer, Pointer);
     };
 }
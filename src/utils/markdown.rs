@@ -0,0 +1,521 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    DisplayProxy, FmtFn, FmtHandler, FmtTrait, Table, TableRow, TableStyle, TextFilter,
+    fmt_filtered, impl_filter_handler, write_escaped,
+};
+
+/// 行首的状态，用于判断字符是否会被解析为块级标记。
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LineState {
+    /// 行首，或其后只有空格。
+    Start,
+    /// 行首的数字之后，`.` 与 `)` 会构成有序列表标记。
+    Digits,
+    Other,
+}
+
+struct MdEscapeFilter {
+    state: LineState,
+}
+
+impl TextFilter for MdEscapeFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            let escape = matches!(
+                (self.state, c),
+                (
+                    _,
+                    '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~' | '&'
+                ) | (LineState::Start, '-' | '+' | '=')
+                    | (LineState::Digits, '.' | ')')
+            );
+            self.state = match (self.state, c) {
+                (_, '\n') => LineState::Start,
+                (LineState::Start, ' ') => LineState::Start,
+                (LineState::Start | LineState::Digits, '0'..='9') => LineState::Digits,
+                _ => LineState::Other,
+            };
+            if escape {
+                out.write_str(&s[start..i])?;
+                out.write_str("\\")?;
+                start = i;
+            }
+        }
+        out.write_str(&s[start..])
+    }
+}
+
+/// 对内部处理器的输出进行 Markdown 转义的格式化处理器
+///
+/// 以反斜杠转义可能被解析为行内标记的字符（`` \ ` * _ [ ] < > # | ~ & ``），
+/// 以及位于行首、可能被解析为列表或标题下划线的 `-`、`+`、`=` 与有序列表标记中的 `.`、`)`.
+/// 其余字符原样输出，使结果在 CommonMark 中按字面显示。
+///
+/// # 类型参数
+/// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, MdEscape};
+/// let s = "*not* a [link]";
+/// assert_eq!(format!("{}", s.fmt_as::<MdEscape>()), r"\*not\* a \[link\]");
+/// assert_eq!(format!("{}", "1. one\n- two".fmt_as::<MdEscape>()), "1\\. one\n\\- two");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MdEscape<Handler = DisplayProxy>(pub Handler);
+
+impl_filter_handler!(
+    MdEscape,
+    |this| MdEscapeFilter {
+        state: LineState::Start,
+    },
+    this.0
+);
+
+/// 统计最长的连续反引号。
+struct BacktickScan {
+    run: usize,
+    longest: usize,
+}
+
+impl TextFilter for BacktickScan {
+    #[inline]
+    fn write_str(&mut self, _: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        for c in s.chars() {
+            self.run = if c == '`' { self.run + 1 } else { 0 };
+            self.longest = self.longest.max(self.run);
+        }
+        Ok(())
+    }
+}
+
+/// 原样输出，并记录输出是否以换行符结尾（空输出视为是）。
+struct TrackLineEnd {
+    at_line_start: bool,
+}
+
+impl TextFilter for TrackLineEnd {
+    #[inline]
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        if let Some(last) = s.chars().next_back() {
+            self.at_line_start = last == '\n';
+        }
+        out.write_str(s)
+    }
+}
+
+fn write_fence(f: &mut ::core::fmt::Formatter<'_>, len: usize) -> ::core::fmt::Result {
+    (0..len).try_for_each(|_| f.write_str("`"))
+}
+
+/// 将内部处理器的输出包装为 Markdown 围栏代码块的格式化处理器
+///
+/// 围栏由反引号组成，长度至少为 3, 且总比内容中最长的连续反引号多一个，因此内容无需转义。
+/// 内容不以换行符结尾时，在结束围栏前补充换行符。
+/// 为确定围栏长度，内部处理器会被调用两次，不分配内存。
+///
+/// # 字段
+/// - `lang`: 信息字符串（通常为语言名），为空时不输出。
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, MdCodeBlock};
+/// let code = "let s = \"```\";";
+/// assert_eq!(
+///     format!("{}", code.fmt_by(MdCodeBlock::new("rust"))),
+///     "````rust\nlet s = \"```\";\n````"
+/// );
+/// ```
+/// # 注意
+/// - `lang` 不应包含反引号或换行符。
+#[derive(Debug, Clone, Copy)]
+pub struct MdCodeBlock<'a, Handler = DisplayProxy> {
+    pub lang: &'a str,
+    pub handler: Handler,
+}
+
+impl<'a> MdCodeBlock<'a> {
+    /// 以 [`DisplayProxy`] 为内部处理器构造。
+    #[inline]
+    pub const fn new(lang: &'a str) -> Self {
+        Self {
+            lang,
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl ::core::default::Default for MdCodeBlock<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl<'a, Handler> MdCodeBlock<'a, Handler> {
+    /// 替换内部处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> MdCodeBlock<'a, NewHandler> {
+        MdCodeBlock {
+            lang: self.lang,
+            handler,
+        }
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for MdCodeBlock<'_, Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        let mut scan = BacktickScan { run: 0, longest: 0 };
+        fmt_filtered(&mut scan, data, self.handler, f, fmt_trait)?;
+        let fence = (scan.longest + 1).max(3);
+        write_fence(f, fence)?;
+        f.write_str(self.lang)?;
+        f.write_str("\n")?;
+        let mut content = TrackLineEnd {
+            at_line_start: true,
+        };
+        fmt_filtered(&mut content, data, self.handler, f, fmt_trait)?;
+        if !content.at_line_start {
+            f.write_str("\n")?;
+        }
+        write_fence(f, fence)
+    }
+}
+
+/// 在除第一行外的每个非空行前写入缩进。
+struct IndentFilter {
+    indent: usize,
+    at_line_start: bool,
+}
+
+impl TextFilter for IndentFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        for line in s.split_inclusive('\n') {
+            if ::core::mem::take(&mut self.at_line_start) && line != "\n" {
+                (0..self.indent).try_for_each(|_| out.write_str(" "))?;
+            }
+            out.write_str(line)?;
+            self.at_line_start = line.ends_with('\n');
+        }
+        Ok(())
+    }
+}
+
+/// 用于将迭代器元素格式化为 Markdown 列表的格式化处理器
+///
+/// 每个元素占一项，项之间以换行符分隔。元素经 `handler` 格式化；其中的多行内容会缩进至列表标记之后，
+/// 从而仍属于同一项。
+///
+/// # 字段
+/// - `ordered`: 是否为有序列表。无序列表以 `- ` 为标记，有序列表以 `1. ` 等为标记。
+/// - `start`: 有序列表的起始序号。
+/// - `handler`: 元素的格式化处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{DisplayProxy, FmtBy, MdEscape, MdList};
+/// let items = ["first", "second\nline", "*third*"];
+/// assert_eq!(
+///     format!("{}", items.fmt_by(MdList::ordered(9).with_handler(MdEscape(DisplayProxy)))),
+///     "9. first\n10. second\n    line\n11. \\*third\\*"
+/// );
+/// assert_eq!(format!("{}", items[..2].fmt_by(MdList::new())), "- first\n- second\n  line");
+/// ```
+/// # 注意
+/// - 要求 `&Iter` 实现 [`IntoIterator`], 且 `handler` 可以格式化其元素。
+#[derive(Debug, Clone, Copy)]
+pub struct MdList<Handler = DisplayProxy> {
+    pub ordered: bool,
+    pub start: usize,
+    pub handler: Handler,
+}
+
+impl MdList {
+    /// 无序列表。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            ordered: false,
+            start: 1,
+            handler: DisplayProxy,
+        }
+    }
+    /// 自 `start` 起编号的有序列表。
+    #[inline]
+    pub const fn ordered(start: usize) -> Self {
+        Self {
+            ordered: true,
+            start,
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl ::core::default::Default for MdList {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Handler> MdList<Handler> {
+    /// 替换元素的格式化处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> MdList<NewHandler> {
+        MdList {
+            ordered: self.ordered,
+            start: self.start,
+            handler,
+        }
+    }
+}
+
+impl<Iter, Handler> FmtHandler<Iter> for MdList<Handler>
+where
+    for<'a> &'a Iter: ::core::iter::IntoIterator,
+    Iter: ?::core::marker::Sized,
+    for<'a> Handler: FmtHandler<<&'a Iter as ::core::iter::IntoIterator>::Item>,
+{
+    fn fmt(&self, data: &Iter, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        for (i, item) in data.into_iter().enumerate() {
+            if i != 0 {
+                f.write_str("\n")?;
+            }
+            let indent = if self.ordered {
+                let n = self.start + i;
+                write!(f, "{}. ", n)?;
+                n.checked_ilog10().unwrap_or(0) as usize + 3
+            } else {
+                f.write_str("- ")?;
+                2
+            };
+            let filter = IndentFilter {
+                indent,
+                at_line_start: false,
+            };
+            fmt_filtered(filter, &item, self.handler, f, FmtTrait::Display)?;
+        }
+        Ok(())
+    }
+}
+
+/// 转义单元格中的 `|` 并将换行符写作 `<br>`, 以免破坏表格结构。
+fn md_cell(
+    row: &dyn TableRow,
+    index: usize,
+    f: &mut ::core::fmt::Formatter<'_>,
+) -> ::core::fmt::Result {
+    struct CellFilter;
+    impl TextFilter for CellFilter {
+        #[inline]
+        fn write_str(
+            &mut self,
+            out: &mut ::core::fmt::Formatter<'_>,
+            s: &str,
+        ) -> ::core::fmt::Result {
+            write_escaped(
+                out,
+                s,
+                |c| matches!(c, '|' | '\n' | '\r'),
+                |c, out| {
+                    out.write_str(match c {
+                        '|' => "\\|",
+                        '\n' => "<br>",
+                        _ => "",
+                    })
+                },
+            )
+        }
+    }
+    let content = FmtFn(|f: &mut ::core::fmt::Formatter<'_>| row.fmt_cell(index, f));
+    fmt_filtered(CellFilter, &content, DisplayProxy, f, FmtTrait::Display)
+}
+
+/// 用于将行的迭代器格式化为 Markdown 表格的格式化处理器
+///
+/// 与 [`Table`] 的 [`TableStyle::Markdown`] 样式相同，但会转义单元格中的 `|`, 并将换行符写作 `<br>`,
+/// 因此任意内容都不会破坏表格结构。单元格中的其他 Markdown 标记保持原样；需按字面显示时，
+/// 可将单元格包装为 [`MdEscape`] 的持有者。
+///
+/// # 类型参数
+/// - `Header`: 表头的类型，需实现 [`TableRow`].
+///
+/// # 字段
+/// - `header`: 表头，为 [`None`] 时输出空表头。
+/// - `align`: 各列的对齐方式，未指定的列不标记对齐方式。
+///
+/// # 示例
+/// ``` rust
+/// use core::fmt::Alignment;
+/// use wfu::{FmtBy, MdTable};
+/// let rows = [("a|b", 1), ("多行\n内容", 20)];
+/// let table = MdTable::new()
+///     .with_header(["key", "value"])
+///     .with_align(&[Alignment::Left, Alignment::Right]);
+/// assert_eq!(
+///     format!("{}", rows.fmt_by(table)),
+///     "\
+/// | key          | value |
+/// | :----------- | ----: |
+/// | a\\|b         |     1 |
+/// | 多行<br>内容 |    20 |"
+/// );
+/// ```
+/// # 注意
+/// - 要求 `&Iter` 实现 [`IntoIterator`], 且其元素实现 [`TableRow`]; 其余限制同 [`Table`].
+#[derive(Debug, Clone, Copy)]
+pub struct MdTable<'a, Header = &'static [&'static str]> {
+    pub header: Option<Header>,
+    pub align: &'a [::core::fmt::Alignment],
+}
+
+impl MdTable<'_> {
+    /// 无表头、不标记对齐方式。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            header: None,
+            align: &[],
+        }
+    }
+}
+
+impl ::core::default::Default for MdTable<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Header> MdTable<'a, Header> {
+    /// 设置表头。
+    #[inline]
+    pub fn with_header<NewHeader: TableRow>(self, header: NewHeader) -> MdTable<'a, NewHeader> {
+        MdTable {
+            header: Some(header),
+            align: self.align,
+        }
+    }
+    /// 设置各列的对齐方式。
+    #[inline]
+    pub fn with_align(self, align: &'a [::core::fmt::Alignment]) -> Self {
+        Self { align, ..self }
+    }
+}
+
+impl<Iter, Header> FmtHandler<Iter> for MdTable<'_, Header>
+where
+    for<'a> &'a Iter: ::core::iter::IntoIterator,
+    Iter: ?::core::marker::Sized,
+    for<'a> <&'a Iter as ::core::iter::IntoIterator>::Item: TableRow,
+    Header: TableRow + ::core::marker::Copy,
+{
+    #[inline]
+    fn fmt(&self, data: &Iter, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let table = Table {
+            header: self.header,
+            align: self.align,
+            style: TableStyle::Markdown,
+        };
+        table.fmt_rows(data, f, md_cell)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy, Joined};
+
+    #[test]
+    fn test_md_escape() {
+        assert_eq!(
+            format!("{}", "# a_b <c> & `d`".fmt_as::<MdEscape>()),
+            r"\# a\_b \<c\> \& \`d\`"
+        );
+        // 行首的列表与标题标记
+        assert_eq!(
+            format!("{}", "  + x\n12) y\n===\na - b 3.5".fmt_as::<MdEscape>()),
+            "  \\+ x\n12\\) y\n\\===\na - b 3.5"
+        );
+        // 分段写入时保持行首状态
+        let parts = ["1", "2", ". item"];
+        assert_eq!(
+            format!("{}", parts.fmt_by(MdEscape(Joined("")))),
+            "12\\. item"
+        );
+    }
+
+    #[test]
+    fn test_md_code_block() {
+        assert_eq!(format!("{}", "".fmt_as::<MdCodeBlock>()), "```\n```");
+        assert_eq!(
+            format!("{}", "fn main() {}\n".fmt_by(MdCodeBlock::new("rust"))),
+            "```rust\nfn main() {}\n```"
+        );
+        let nested = "`````\n``";
+        assert_eq!(
+            format!("{}", nested.fmt_as::<MdCodeBlock>()),
+            "``````\n`````\n``\n``````"
+        );
+    }
+
+    #[test]
+    fn test_md_list_and_table() {
+        // 测试嵌套列表
+        let inner = ["a", "b"];
+        let items = [FmtFn(|f: &mut ::core::fmt::Formatter| {
+            write!(f, "outer\n{}", inner.fmt_by(MdList::new()))
+        })];
+        assert_eq!(
+            format!("{}", items.fmt_by(MdList::ordered(1))),
+            "1. outer\n   - a\n   - b"
+        );
+        // 空行不缩进
+        assert_eq!(format!("{}", ["p\n\nq"].fmt_as::<MdList>()), "- p\n\n  q");
+        let empty: [&str; 0] = [];
+        assert_eq!(format!("{}", empty.fmt_as::<MdList>()), "");
+
+        // 测试无表头的表格
+        let rows = [["x"]];
+        assert_eq!(
+            format!("{}", rows.fmt_as::<MdTable>()),
+            "|     |\n| --- |\n| x   |"
+        );
+    }
+}
@@ -20,15 +20,71 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+mod ansi;
+mod boxed;
+mod bytes;
+mod color_debug;
 mod debug_map;
+mod decode;
+#[cfg(feature = "alloc")]
+mod diff;
+mod encoding;
+mod escape;
+mod filter;
 mod fmt_fn;
+mod hex_dump;
+mod invisibles;
 mod joined;
+mod justify;
+mod line_numbers;
+mod literal;
 mod r#macro;
+mod markdown;
+mod padded;
+mod pretty;
+mod progress;
 mod proxy;
+mod re_debug;
 mod repeat;
+mod run_length;
+mod side_by_side;
+mod snippet;
+mod table;
+mod tree;
+mod whitespace;
+mod width;
+mod wrap;
 
+pub use ansi::*;
+pub use boxed::*;
+pub use bytes::*;
+pub use color_debug::*;
 pub use debug_map::*;
+pub use decode::*;
+#[cfg(feature = "alloc")]
+pub use diff::*;
+pub use encoding::*;
+pub use escape::*;
+pub use filter::*;
 pub use fmt_fn::*;
+pub use hex_dump::*;
+pub use invisibles::*;
 pub use joined::*;
+pub use justify::*;
+pub use line_numbers::*;
+pub use literal::*;
+pub use markdown::*;
+pub use padded::*;
+pub use pretty::*;
+pub use progress::*;
 pub use proxy::*;
+pub use re_debug::*;
 pub use repeat::*;
+pub use run_length::*;
+pub use side_by_side::*;
+pub use snippet::*;
+pub use table::*;
+pub use tree::*;
+pub use whitespace::*;
+pub use width::*;
+pub use wrap::*;
@@ -0,0 +1,268 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DisplayProxy, FmtFn, FmtHandler, FmtOptions, FmtTrait};
+
+/// 按显示宽度填充内部处理器输出的格式化处理器
+///
+/// 标准库的填充按字符个数计算宽度，中日韩文字等宽字符因而无法对齐；此处理器改按 [`str_width`](crate::str_width) 测量，
+/// 再以格式中的填充字符与对齐方式补足至格式中的宽度。未指定宽度时直接调用内部处理器。
+///
+/// # 类型参数
+/// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, Padded};
+/// assert_eq!(format!("[{:6}]", "中文".fmt_as::<Padded>()), "[中文  ]");
+/// assert_eq!(format!("[{:*^7}]", "中文".fmt_as::<Padded>()), "[*中文**]");
+/// assert_eq!(format!("[{:>3}]", "中文".fmt_as::<Padded>()), "[中文]");
+/// ```
+/// # 注意
+/// - 内部处理器不会收到宽度；`#` 标志与精度照常传递。
+/// - 未指定对齐方式时总是左对齐：内部处理器的输出类型未知，无法像标准库那样令数字默认右对齐，需要时请显式指定 `>`.
+/// - 与标准库相同，`0` 标志忽略填充字符与对齐方式，在开头的符号及 `#` 产生的进制前缀之后补零。
+/// - 未启用 `alloc` 特性时，内部处理器会被调用两次：一次测量宽度，一次输出；启用后只调用一次，输出暂存于堆上。
+/// - 填充字符按每个占一列计算。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Padded<Handler = DisplayProxy>(pub Handler);
+
+impl<T, Handler> FmtHandler<T> for Padded<Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        let Some(width) = f.width().filter(|_| f.sign_aware_zero_pad()) else {
+            return pad_output(f, |f, options| options.write_by(f, data, self.0, fmt_trait));
+        };
+        let options = FmtOptions::of(f);
+        let content = FmtFn(|f: &mut ::core::fmt::Formatter<'_>| {
+            options.write_by(f, data, self.0, fmt_trait)
+        });
+        #[cfg(feature = "alloc")]
+        {
+            use ::core::fmt::Write;
+            let mut buf = ::alloc::string::String::new();
+            write!(buf, "{}", content)?;
+            let zeros = width.saturating_sub(crate::str_width(&buf));
+            ZeroPad::new(f, zeros, options.alternate).write(&buf)
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            use ::core::fmt::Write;
+            let mut counter = crate::WidthCounter::default();
+            write!(counter, "{}", content)?;
+            ZeroPad::new(f, width.saturating_sub(counter.0), options.alternate).write(content)
+        }
+    }
+}
+
+/// 在内容开头的符号及 `#` 产生的进制前缀（`0x`、`0o`、`0b`）之后补零，与标准库的 `0` 标志相同。
+struct ZeroPad<'a, 'f> {
+    out: &'a mut ::core::fmt::Formatter<'f>,
+    zeros: usize,
+    alternate: bool,
+    /// 可能属于符号与前缀、尚未输出的开头部分。
+    prefix: [u8; 3],
+    len: usize,
+    done: bool,
+}
+
+impl<'a, 'f> ZeroPad<'a, 'f> {
+    #[inline]
+    fn new(out: &'a mut ::core::fmt::Formatter<'f>, zeros: usize, alternate: bool) -> Self {
+        Self {
+            out,
+            zeros,
+            alternate,
+            prefix: [0; 3],
+            len: 0,
+            done: zeros == 0,
+        }
+    }
+    fn write(mut self, content: impl ::core::fmt::Display) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        write!(self, "{}", content)?;
+        if self.done {
+            return Ok(());
+        }
+        let signed = matches!(self.prefix[0], b'+' | b'-') as usize;
+        self.flush(signed, self.len)
+    }
+    /// 输出开头部分的前 `split` 字节、补零，再输出开头部分中至第 `end` 字节为止的其余部分。
+    fn flush(&mut self, split: usize, end: usize) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        let prefix = ::core::str::from_utf8(&self.prefix[..end]).map_err(|_| ::core::fmt::Error)?;
+        self.out.write_str(&prefix[..split])?;
+        (0..self.zeros).try_for_each(|_| self.out.write_char('0'))?;
+        self.done = true;
+        self.out.write_str(&prefix[split..])
+    }
+}
+
+impl ::core::fmt::Write for ZeroPad<'_, '_> {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        for (i, c) in s.char_indices() {
+            if self.done {
+                return self.out.write_str(&s[i..]);
+            }
+            let signed = matches!(self.prefix[0], b'+' | b'-') as usize;
+            let radix = self.alternate && self.len == signed + 1 && self.prefix[signed] == b'0';
+            match c {
+                '+' | '-' if self.len == 0 => {}
+                '0' if self.alternate && self.len == signed => {}
+                'x' | 'o' | 'b' if radix => {
+                    self.prefix[self.len] = c as u8;
+                    self.len += 1;
+                    self.flush(self.len, self.len)?;
+                    continue;
+                }
+                _ => {
+                    self.flush(signed, self.len)?;
+                    self.out.write_char(c)?;
+                    continue;
+                }
+            }
+            self.prefix[self.len] = c as u8;
+            self.len += 1;
+        }
+        Ok(())
+    }
+}
+
+/// 按 `f` 的填充字符与对齐方式，将显示宽度为 `content_width` 的内容补足至 `width`, 默认左对齐。
+pub(crate) fn pad(
+    f: &mut ::core::fmt::Formatter<'_>,
+    width: usize,
+    content_width: usize,
+    content: impl FnOnce(&mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+) -> ::core::fmt::Result {
+    use ::core::fmt::Write;
+    let padding = width.saturating_sub(content_width);
+    let (before, after) = match f.align() {
+        Some(::core::fmt::Alignment::Right) => (padding, 0),
+        Some(::core::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    (0..before).try_for_each(|_| f.write_char(fill))?;
+    content(f)?;
+    (0..after).try_for_each(|_| f.write_char(fill))
+}
+
+/// 以 `f` 的选项（见 [`FmtOptions`]）调用 `content`, 并按 `f` 的宽度、填充字符与对齐方式填充其输出，宽度按 [`str_width`](crate::str_width) 测量。
+///
+/// 未指定宽度时直接输出；否则启用 `alloc` 特性时输出暂存于堆上，未启用时 `content` 会被调用两次：一次测量宽度，一次输出。
+pub(crate) fn pad_output(
+    f: &mut ::core::fmt::Formatter<'_>,
+    content: impl Fn(&mut ::core::fmt::Formatter<'_>, FmtOptions) -> ::core::fmt::Result,
+) -> ::core::fmt::Result {
+    use ::core::fmt::Write;
+    let options = FmtOptions::of(f);
+    let Some(width) = f.width() else {
+        return content(f, options);
+    };
+    let content = FmtFn(|f: &mut ::core::fmt::Formatter<'_>| content(f, options));
+    #[cfg(feature = "alloc")]
+    {
+        let mut buf = ::alloc::string::String::new();
+        write!(buf, "{}", content)?;
+        pad(f, width, crate::str_width(&buf), |f| f.write_str(&buf))
+    }
+    #[cfg(not(feature = "alloc"))]
+    {
+        let mut counter = crate::WidthCounter::default();
+        write!(counter, "{}", content)?;
+        pad(f, width, counter.0, |f| {
+            ::core::fmt::Display::fmt(&content, f)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bytes, FmtAs, FmtBy, Joined};
+
+    #[test]
+    fn test_padded() {
+        // 测试未指定宽度
+        assert_eq!(format!("{}", "中文".fmt_as::<Padded>()), "中文");
+        // 测试对齐方式
+        let padded = Padded(DisplayProxy);
+        assert_eq!(format!("|{:<5}|", "한국".fmt_by(padded)), "|한국 |");
+        assert_eq!(format!("|{:>5}|", "한국".fmt_by(padded)), "| 한국|");
+        assert_eq!(format!("|{:-^8}|", "한국".fmt_by(padded)), "|--한국--|");
+
+        // 测试精度与格式化特型传递给内部处理器
+        let bytes = [0xab, 0xcd];
+        let proxy = bytes.fmt_by(Padded(Bytes::new()));
+        assert_eq!(format!("{:>6X}", proxy), "  ABCD");
+
+        // 测试与标准库一致：显式对齐与 `0` 标志
+        assert_eq!(
+            format!("{:>8.2}", 1.5.fmt_as::<Padded>()),
+            format!("{:>8.2}", 1.5),
+        );
+        assert_eq!(
+            format!("{:08.2}", (-1.5).fmt_as::<Padded>()),
+            format!("{:08.2}", -1.5),
+        );
+        assert_eq!(
+            format!("{:<+05}", 7.fmt_as::<Padded>()),
+            format!("{:<+05}", 7),
+        );
+        assert_eq!(
+            format!("{:#010x}", 255.fmt_by(Padded(crate::LowerHexProxy))),
+            format!("{:#010x}", 255),
+        );
+        assert_eq!(
+            format!("{:#06b}", 2.fmt_by(Padded(crate::BinaryProxy))),
+            format!("{:#06b}", 2),
+        );
+        assert_eq!(
+            format!("{:02}", 123.fmt_as::<Padded>()),
+            format!("{:02}", 123),
+        );
+        assert_eq!(
+            format!("{:6}", "ab".fmt_as::<Padded>()),
+            format!("{:6}", "ab"),
+        );
+        // 未指定对齐方式时左对齐，标准库对数字则右对齐
+        assert_eq!(format!("{:8.2}", 1.5.fmt_as::<Padded>()), "1.50    ");
+
+        // 测试与 Joined 组合
+        let words = ["一", "二", "三"];
+        let proxy = words.fmt_by(Padded(Joined("、")));
+        assert_eq!(format!("{:>12}", proxy), "  一、二、三");
+    }
+}
@@ -0,0 +1,789 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DebugProxy, DisplayProxy, FmtFn, FmtHandler, WidthCounter, str_width};
+
+/// 文档的动态片段，用于描述数量在格式化时才确定的子文档（如集合的元素），见 [`Doc::Items`].
+///
+/// 渲染时可能被多次调用：排版时为判断分组能否放在一行，会向后测量尚未输出的片段。
+/// 已为形如 `Fn(&mut dyn FnMut(&Doc) -> fmt::Result) -> fmt::Result` 的闭包实现。
+pub trait DocSource {
+    /// 依次以各子文档调用 `emit`; `emit` 返回错误时应立即返回该错误。
+    fn for_each(
+        &self,
+        emit: &mut dyn FnMut(&Doc<'_>) -> ::core::fmt::Result,
+    ) -> ::core::fmt::Result;
+}
+
+impl<F> DocSource for F
+where
+    F: Fn(&mut dyn FnMut(&Doc<'_>) -> ::core::fmt::Result) -> ::core::fmt::Result,
+{
+    #[inline]
+    fn for_each(
+        &self,
+        emit: &mut dyn FnMut(&Doc<'_>) -> ::core::fmt::Result,
+    ) -> ::core::fmt::Result {
+        self(emit)
+    }
+}
+
+/// Wadler 风格的排版文档
+///
+/// 文档由文本与可选的换行组成：[`Doc::Group`] 中的内容若能在剩余宽度内放下，其中的 [`Doc::Line`] 输出为空格、
+/// [`Doc::SoftLine`] 不输出；否则二者均输出为换行，并缩进至所在 [`Doc::Nest`] 的层级。
+/// 文档以借用构造，不分配内存。
+///
+/// 实现了 [`Display`](::core::fmt::Display), 目标宽度取自 `{:80}` 这样的宽度参数，默认为 [`Doc::DEFAULT_WIDTH`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::Doc;
+/// let args = [Doc::Text("first"), Doc::Text(","), Doc::Line, Doc::Text("second")];
+/// let body = [Doc::SoftLine, Doc::Concat(&args)];
+/// let call = [
+///     Doc::Text("call("),
+///     Doc::Nest(4, &Doc::Concat(&body)),
+///     Doc::SoftLine,
+///     Doc::Text(")"),
+/// ];
+/// let doc = Doc::Group(&Doc::Concat(&call));
+/// assert_eq!(format!("{}", doc), "call(first, second)");
+/// assert_eq!(format!("{:10}", doc), "call(\n    first,\n    second\n)");
+/// ```
+/// # 注意
+/// - 判断能否放下时只向后测量到下一个必然换行处，与 Wadler 的算法一致，不回溯。
+/// - [`Doc::Fmt`] 的输出被视为一段文本，不参与断行；其中的换行符仅用于更新当前列。
+#[derive(Clone, Copy)]
+pub enum Doc<'a> {
+    /// 不含换行符的文本。
+    Text(&'a str),
+    /// 任意可显示的值，作为一段文本输出。
+    Fmt(&'a dyn ::core::fmt::Display),
+    /// 不换行时为一个空格。
+    Line,
+    /// 不换行时为空。
+    SoftLine,
+    /// 总是换行，所在分组均不能放在一行。
+    HardLine,
+    /// 仅在所在分组换行时输出的文本，常用于末尾的分隔符。
+    IfBreak(&'a str),
+    Concat(&'a [Doc<'a>]),
+    Group(&'a Doc<'a>),
+    /// 内容换行后增加的缩进。
+    Nest(usize, &'a Doc<'a>),
+    /// 动态的子文档序列，依次连接。
+    Items(&'a dyn DocSource),
+}
+
+impl<'a> Doc<'a> {
+    /// 未指定宽度时的目标宽度。
+    pub const DEFAULT_WIDTH: usize = 80;
+
+    /// 以目标宽度 `width` 将文档写入 `f`.
+    pub fn render(&self, f: &mut ::core::fmt::Formatter<'_>, width: usize) -> ::core::fmt::Result {
+        Printer::new(f, width).print(self, 0, false, None)
+    }
+}
+
+impl ::core::fmt::Debug for Doc<'_> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Text(s) => f.debug_tuple("Text").field(s).finish(),
+            Self::Fmt(value) => f
+                .debug_tuple("Fmt")
+                .field(&format_args!("{}", value))
+                .finish(),
+            Self::Line => f.write_str("Line"),
+            Self::SoftLine => f.write_str("SoftLine"),
+            Self::HardLine => f.write_str("HardLine"),
+            Self::IfBreak(s) => f.debug_tuple("IfBreak").field(s).finish(),
+            Self::Concat(docs) => f.debug_tuple("Concat").field(docs).finish(),
+            Self::Group(doc) => f.debug_tuple("Group").field(doc).finish(),
+            Self::Nest(indent, doc) => f.debug_tuple("Nest").field(indent).field(doc).finish(),
+            Self::Items(_) => f.write_str("Items(..)"),
+        }
+    }
+}
+
+impl ::core::fmt::Display for Doc<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let width = f.width().unwrap_or(Self::DEFAULT_WIDTH);
+        self.render(f, width)
+    }
+}
+
+/// 尚未输出的后续文档，以栈上的链表存放，供判断分组能否放下时向后测量。
+#[derive(Clone, Copy)]
+enum Pending<'r> {
+    Docs(&'r [Doc<'r>]),
+    /// 跳过前若干个子文档。
+    Items(&'r dyn DocSource, usize),
+}
+
+struct Rest<'r> {
+    pending: Pending<'r>,
+    flat: bool,
+    next: Option<&'r Rest<'r>>,
+}
+
+/// 测量 `doc` 的宽度并从 `remaining` 中扣除。遇到换行时返回 `Some(true)`, 超出时返回 `Some(false)`.
+fn measure(doc: &Doc<'_>, flat: bool, remaining: &mut usize) -> Option<bool> {
+    let mut take = |width: usize| match remaining.checked_sub(width) {
+        Some(rest) => {
+            *remaining = rest;
+            None
+        }
+        None => Some(false),
+    };
+    match *doc {
+        Doc::Text(s) => take(str_width(s)),
+        Doc::Fmt(value) => {
+            use ::core::fmt::Write;
+            let mut counter = WidthCounter::default();
+            let _ = write!(counter, "{}", value);
+            take(counter.0)
+        }
+        Doc::Line if flat => take(1),
+        Doc::SoftLine if flat => None,
+        Doc::HardLine if flat => Some(false),
+        Doc::Line | Doc::SoftLine | Doc::HardLine => Some(true),
+        Doc::IfBreak(s) => {
+            if flat {
+                None
+            } else {
+                take(str_width(s))
+            }
+        }
+        Doc::Concat(docs) => docs.iter().find_map(|doc| measure(doc, flat, remaining)),
+        Doc::Group(doc) | Doc::Nest(_, doc) => measure(doc, flat, remaining),
+        Doc::Items(source) => measure_items(source, 0, flat, remaining),
+    }
+}
+
+fn measure_items(
+    source: &dyn DocSource,
+    skip: usize,
+    flat: bool,
+    remaining: &mut usize,
+) -> Option<bool> {
+    let mut index = 0;
+    let mut result = None;
+    // 得出结论后以错误中止遍历
+    let _ = source.for_each(&mut |doc| {
+        index += 1;
+        if index <= skip {
+            return Ok(());
+        }
+        result = measure(doc, flat, remaining);
+        match result {
+            Some(_) => Err(::core::fmt::Error),
+            None => Ok(()),
+        }
+    });
+    result
+}
+
+struct Printer<'p, 'f> {
+    f: &'p mut ::core::fmt::Formatter<'f>,
+    width: usize,
+    column: usize,
+    /// 换行后尚未输出的缩进，推迟至下一段文本之前输出，以免产生行尾空白。
+    indent: Option<usize>,
+}
+
+impl<'p, 'f> Printer<'p, 'f> {
+    #[inline]
+    fn new(f: &'p mut ::core::fmt::Formatter<'f>, width: usize) -> Self {
+        Self {
+            f,
+            width,
+            column: 0,
+            indent: None,
+        }
+    }
+    fn newline(&mut self, indent: usize) -> ::core::fmt::Result {
+        self.f.write_str("\n")?;
+        self.column = 0;
+        self.indent = Some(indent);
+        Ok(())
+    }
+    /// 判断 `doc` 能否在当前行的剩余宽度内放下。
+    fn fits(&self, doc: &Doc<'_>, rest: Option<&Rest<'_>>) -> bool {
+        let column = self.indent.unwrap_or(self.column);
+        let Some(mut remaining) = self.width.checked_sub(column) else {
+            return false;
+        };
+        if let Some(fits) = measure(doc, true, &mut remaining) {
+            return fits;
+        }
+        let mut rest = rest;
+        while let Some(node) = rest {
+            let result = match node.pending {
+                Pending::Docs(docs) => docs
+                    .iter()
+                    .find_map(|doc| measure(doc, node.flat, &mut remaining)),
+                Pending::Items(source, skip) => {
+                    measure_items(source, skip, node.flat, &mut remaining)
+                }
+            };
+            if let Some(fits) = result {
+                return fits;
+            }
+            rest = node.next;
+        }
+        true
+    }
+    fn print(
+        &mut self,
+        doc: &Doc<'_>,
+        indent: usize,
+        flat: bool,
+        rest: Option<&Rest<'_>>,
+    ) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        match *doc {
+            Doc::Text(s) => self.write_str(s),
+            Doc::Fmt(value) => write!(self, "{}", value),
+            Doc::Line if flat => self.write_str(" "),
+            Doc::SoftLine if flat => Ok(()),
+            Doc::Line | Doc::SoftLine | Doc::HardLine => self.newline(indent),
+            Doc::IfBreak(s) => {
+                if flat {
+                    Ok(())
+                } else {
+                    self.write_str(s)
+                }
+            }
+            Doc::Concat(docs) => docs.iter().enumerate().try_for_each(|(i, doc)| {
+                let rest = Rest {
+                    pending: Pending::Docs(&docs[i + 1..]),
+                    flat,
+                    next: rest,
+                };
+                self.print(doc, indent, flat, Some(&rest))
+            }),
+            Doc::Group(doc) => {
+                let flat = flat || self.fits(doc, rest);
+                self.print(doc, indent, flat, rest)
+            }
+            Doc::Nest(extra, doc) => self.print(doc, indent + extra, flat, rest),
+            Doc::Items(source) => {
+                let mut index = 0;
+                source.for_each(&mut |doc| {
+                    index += 1;
+                    let rest = Rest {
+                        pending: Pending::Items(source, index),
+                        flat,
+                        next: rest,
+                    };
+                    self.print(doc, indent, flat, Some(&rest))
+                })
+            }
+        }
+    }
+}
+
+impl ::core::fmt::Write for Printer<'_, '_> {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        if let Some(indent) = self.indent.take() {
+            write!(self.f, "{:indent$}", "")?;
+            self.column = indent;
+        }
+        self.f.write_str(s)?;
+        match s.rsplit_once('\n') {
+            Some((_, last)) => self.column = str_width(last),
+            None => self.column += str_width(s),
+        }
+        Ok(())
+    }
+}
+
+/// 以闭包构造 [`Doc`] 并排版输出的格式化处理器
+///
+/// `layout` 形如 `Fn(&T, &mut dyn FnMut(&Doc) -> fmt::Result) -> fmt::Result`, 以数据构造文档后交给第二个参数输出。
+/// 多次输出的文档依次连接，共享当前列。
+///
+/// # 字段
+/// - `width`: 目标宽度；为 [`None`] 时取自格式化参数中的宽度，再缺省为 [`Doc::DEFAULT_WIDTH`].
+/// - `layout`: 构造文档的闭包。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{Doc, FmtBy, Pretty};
+/// let pretty = Pretty::new(|pair: &(&str, &str), emit| {
+///     let body = [Doc::Line, Doc::Text(pair.1)];
+///     let docs = [Doc::Text(pair.0), Doc::Text(" ="), Doc::Nest(2, &Doc::Concat(&body))];
+///     emit(&Doc::Group(&Doc::Concat(&docs)))
+/// });
+/// let pair = ("key", "value");
+/// assert_eq!(format!("{}", pair.fmt_by(pretty)), "key = value");
+/// assert_eq!(format!("{:8}", pair.fmt_by(pretty)), "key =\n  value");
+/// ```
+/// # 注意
+/// - 闭包须实现 [`Copy`].
+#[derive(Debug, Clone, Copy)]
+pub struct Pretty<Layout> {
+    pub width: Option<usize>,
+    pub layout: Layout,
+}
+
+impl<Layout> Pretty<Layout> {
+    /// 以 `layout` 构造，目标宽度取自格式化参数。
+    #[inline]
+    pub fn new<T>(layout: Layout) -> Self
+    where
+        T: ?::core::marker::Sized,
+        Layout: Fn(&T, &mut dyn FnMut(&Doc<'_>) -> ::core::fmt::Result) -> ::core::fmt::Result,
+    {
+        Self {
+            width: None,
+            layout,
+        }
+    }
+    /// 设置目标宽度。
+    #[inline]
+    pub fn with_width(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+}
+
+impl<T, Layout> FmtHandler<T> for Pretty<Layout>
+where
+    T: ?::core::marker::Sized,
+    Layout: Fn(&T, &mut dyn FnMut(&Doc<'_>) -> ::core::fmt::Result) -> ::core::fmt::Result
+        + ::core::marker::Copy,
+{
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let width = self.width.or(f.width()).unwrap_or(Doc::DEFAULT_WIDTH);
+        let mut printer = Printer::new(f, width);
+        (self.layout)(data, &mut |doc| printer.print(doc, 0, false, None))
+    }
+}
+
+/// 用于将迭代器元素排版为列表的格式化处理器：放得下时输出在一行，否则每个元素一行
+///
+/// 换行时元素缩进 `indent` 列，每个元素后都带有分隔符，括号各占一行，与 `{:#?}` 的风格相同。
+///
+/// # 字段
+/// - `open`、`close`: 括号，默认为 `[` 与 `]`.
+/// - `separator`: 分隔符，默认为 `,`; 不换行时其后跟一个空格。
+/// - `indent`: 换行时的缩进，默认为 `4`.
+/// - `width`: 目标宽度；为 [`None`] 时取自格式化参数中的宽度，再缺省为 [`Doc::DEFAULT_WIDTH`].
+/// - `handler`: 元素的格式化处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, PrettyList};
+/// let list = [1, 22, 333];
+/// assert_eq!(format!("{}", list.fmt_by(PrettyList::new())), "[1, 22, 333]");
+/// assert_eq!(
+///     format!("{:10}", list.fmt_by(PrettyList::new())),
+///     "[\n    1,\n    22,\n    333,\n]"
+/// );
+/// ```
+/// # 注意
+/// - 要求 `&Iter` 实现 [`IntoIterator`]; 为判断能否放下，元素可能被迭代并格式化多次。
+/// - 元素的输出被视为一段文本，不参与断行。
+/// - [`Joined`](crate::Joined) 总是输出在一行；键值对请使用 [`PrettyMap`].
+#[derive(Debug, Clone, Copy)]
+pub struct PrettyList<'a, Handler = DisplayProxy> {
+    pub open: &'a str,
+    pub close: &'a str,
+    pub separator: &'a str,
+    pub indent: usize,
+    pub width: Option<usize>,
+    pub handler: Handler,
+}
+
+impl PrettyList<'_> {
+    /// 以方括号、逗号、四列缩进与 [`DisplayProxy`] 构造。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            open: "[",
+            close: "]",
+            separator: ",",
+            indent: 4,
+            width: None,
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl ::core::default::Default for PrettyList<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Handler> PrettyList<'a, Handler> {
+    /// 替换括号。
+    #[inline]
+    pub fn with_brackets(self, open: &'a str, close: &'a str) -> Self {
+        Self {
+            open,
+            close,
+            ..self
+        }
+    }
+    /// 替换分隔符。
+    #[inline]
+    pub fn with_separator(self, separator: &'a str) -> Self {
+        Self { separator, ..self }
+    }
+    /// 替换换行时的缩进。
+    #[inline]
+    pub fn with_indent(self, indent: usize) -> Self {
+        Self { indent, ..self }
+    }
+    /// 设置目标宽度。
+    #[inline]
+    pub fn with_width(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+    /// 替换元素的格式化处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> PrettyList<'a, NewHandler> {
+        PrettyList {
+            open: self.open,
+            close: self.close,
+            separator: self.separator,
+            indent: self.indent,
+            width: self.width,
+            handler,
+        }
+    }
+}
+
+/// 将 `items` 产生的各元素排版为列表：放得下时输出在一行，否则每个元素一行。
+fn fmt_list<I>(
+    f: &mut ::core::fmt::Formatter<'_>,
+    [open, close, separator]: [&str; 3],
+    indent: usize,
+    width: Option<usize>,
+    items: impl Fn() -> I,
+) -> ::core::fmt::Result
+where
+    I: ::core::iter::Iterator,
+    I::Item: ::core::fmt::Display,
+{
+    let source = |emit: &mut dyn FnMut(&Doc<'_>) -> ::core::fmt::Result| {
+        let mut items = items().peekable();
+        while let Some(item) = items.next() {
+            if items.peek().is_some() {
+                emit(&Doc::Concat(&[
+                    Doc::Fmt(&item),
+                    Doc::Text(separator),
+                    Doc::Line,
+                ]))?;
+            } else {
+                emit(&Doc::Concat(&[Doc::Fmt(&item), Doc::IfBreak(separator)]))?;
+            }
+        }
+        Ok(())
+    };
+    let body = [Doc::SoftLine, Doc::Items(&source)];
+    let docs = [
+        Doc::Text(open),
+        Doc::Nest(indent, &Doc::Concat(&body)),
+        Doc::SoftLine,
+        Doc::Text(close),
+    ];
+    let width = width.or(f.width()).unwrap_or(Doc::DEFAULT_WIDTH);
+    Doc::Group(&Doc::Concat(&docs)).render(f, width)
+}
+
+impl<Iter, Handler> FmtHandler<Iter> for PrettyList<'_, Handler>
+where
+    for<'a> &'a Iter: ::core::iter::IntoIterator,
+    Iter: ?::core::marker::Sized,
+    for<'a> Handler: FmtHandler<<&'a Iter as ::core::iter::IntoIterator>::Item>,
+{
+    fn fmt(&self, data: &Iter, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let delims = [self.open, self.close, self.separator];
+        fmt_list(f, delims, self.indent, self.width, || {
+            data.into_iter().map(|item| {
+                FmtFn(move |f: &mut ::core::fmt::Formatter<'_>| self.handler.fmt(&item, f))
+            })
+        })
+    }
+}
+
+/// 用于将键值对排版为映射的格式化处理器：放得下时输出在一行，否则每个键值对一行
+///
+/// 排版方式与 [`PrettyList`] 相同，默认输出形如 `{:?}` 的 `{"a": 1, "b": 2}`.
+///
+/// # 字段
+/// - `open`、`close`: 括号，默认为 `{` 与 `}`.
+/// - `separator`: 键值对之间的分隔符，默认为 `,`; 不换行时其后跟一个空格。
+/// - `key_separator`: 键与值之间的分隔符，默认为 `: `.
+/// - `indent`: 换行时的缩进，默认为 `4`.
+/// - `width`: 目标宽度；为 [`None`] 时取自格式化参数中的宽度，再缺省为 [`Doc::DEFAULT_WIDTH`].
+/// - `handler`: 键与值的格式化处理器，默认为 [`DebugProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, PrettyMap};
+/// let map = std::collections::BTreeMap::from([("a", 1), ("b", 22)]);
+/// assert_eq!(format!("{}", map.fmt_by(PrettyMap::new())), r#"{"a": 1, "b": 22}"#);
+/// assert_eq!(
+///     format!("{:12}", map.fmt_by(PrettyMap::new())),
+///     "{\n    \"a\": 1,\n    \"b\": 22,\n}"
+/// );
+/// ```
+/// # 注意
+/// - 与 [`DebugMap`](crate::DebugMap) 相同，要求 `&Iter` 实现 `IntoIterator<Item = (&K, &V)>`; 为判断能否放下，键值对可能被迭代并格式化多次。
+/// - 每个键值对的输出被视为一段文本，不参与断行。
+#[derive(Debug, Clone, Copy)]
+pub struct PrettyMap<'a, Handler = DebugProxy> {
+    pub open: &'a str,
+    pub close: &'a str,
+    pub separator: &'a str,
+    pub key_separator: &'a str,
+    pub indent: usize,
+    pub width: Option<usize>,
+    pub handler: Handler,
+}
+
+impl PrettyMap<'_> {
+    /// 以花括号、逗号、`: `、四列缩进与 [`DebugProxy`] 构造。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            open: "{",
+            close: "}",
+            separator: ",",
+            key_separator: ": ",
+            indent: 4,
+            width: None,
+            handler: DebugProxy,
+        }
+    }
+}
+
+impl ::core::default::Default for PrettyMap<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Handler> PrettyMap<'a, Handler> {
+    /// 替换括号。
+    #[inline]
+    pub fn with_brackets(self, open: &'a str, close: &'a str) -> Self {
+        Self {
+            open,
+            close,
+            ..self
+        }
+    }
+    /// 替换键值对之间的分隔符。
+    #[inline]
+    pub fn with_separator(self, separator: &'a str) -> Self {
+        Self { separator, ..self }
+    }
+    /// 替换键与值之间的分隔符。
+    #[inline]
+    pub fn with_key_separator(self, key_separator: &'a str) -> Self {
+        Self {
+            key_separator,
+            ..self
+        }
+    }
+    /// 替换换行时的缩进。
+    #[inline]
+    pub fn with_indent(self, indent: usize) -> Self {
+        Self { indent, ..self }
+    }
+    /// 设置目标宽度。
+    #[inline]
+    pub fn with_width(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+    /// 替换键与值的格式化处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> PrettyMap<'a, NewHandler> {
+        PrettyMap {
+            open: self.open,
+            close: self.close,
+            separator: self.separator,
+            key_separator: self.key_separator,
+            indent: self.indent,
+            width: self.width,
+            handler,
+        }
+    }
+}
+
+impl<Iter, K, V, Handler> FmtHandler<Iter> for PrettyMap<'_, Handler>
+where
+    for<'a> &'a Iter: ::core::iter::IntoIterator<Item = (&'a K, &'a V)>,
+    Iter: ?::core::marker::Sized,
+    K: ?::core::marker::Sized,
+    V: ?::core::marker::Sized,
+    Handler: FmtHandler<K> + FmtHandler<V>,
+{
+    fn fmt(&self, data: &Iter, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let delims = [self.open, self.close, self.separator];
+        fmt_list(f, delims, self.indent, self.width, || {
+            data.into_iter().map(|(key, value)| {
+                FmtFn(move |f: &mut ::core::fmt::Formatter<'_>| {
+                    self.handler.fmt(key, f)?;
+                    f.write_str(self.key_separator)?;
+                    self.handler.fmt(value, f)
+                })
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FmtBy;
+
+    #[test]
+    fn test_doc() {
+        // 测试嵌套分组：外层换行时内层仍可放在一行
+        let inner_body = [Doc::SoftLine, Doc::Text("b,"), Doc::Line, Doc::Text("c")];
+        let inner = [
+            Doc::Text("("),
+            Doc::Nest(2, &Doc::Concat(&inner_body)),
+            Doc::SoftLine,
+            Doc::Text(")"),
+        ];
+        let inner = Doc::Group(&Doc::Concat(&inner));
+        let outer_body = [Doc::SoftLine, Doc::Text("a,"), Doc::Line, inner];
+        let outer = [
+            Doc::Text("("),
+            Doc::Nest(2, &Doc::Concat(&outer_body)),
+            Doc::SoftLine,
+            Doc::Text(")"),
+        ];
+        let doc = Doc::Group(&Doc::Concat(&outer));
+        assert_eq!(format!("{}", doc), "(a, (b, c))");
+        assert_eq!(format!("{:8}", doc), "(\n  a,\n  (b, c)\n)");
+        assert_eq!(format!("{:5}", doc), "(\n  a,\n  (\n    b,\n    c\n  )\n)");
+
+        // 测试强制换行与行尾无空白
+        let docs = [
+            Doc::Text("x"),
+            Doc::HardLine,
+            Doc::HardLine,
+            Doc::Line,
+            Doc::Text("y"),
+        ];
+        let doc = Doc::Nest(2, &Doc::Concat(&docs));
+        assert_eq!(format!("{}", Doc::Group(&doc)), "x\n\n\n  y");
+        assert_eq!(
+            format!("{:?}", Doc::Group(&Doc::Text("t"))),
+            r#"Group(Text("t"))"#
+        );
+    }
+
+    #[test]
+    fn test_pretty_list() {
+        // 测试后续文本计入宽度
+        let list = ["ab", "cd"];
+        let tail = FmtFn(|f: &mut ::core::fmt::Formatter<'_>| {
+            write!(f, "{};", list.fmt_by(PrettyList::new()))
+        });
+        assert_eq!(format!("{}", tail), "[ab, cd];");
+        let list_doc = FmtFn(|f: &mut ::core::fmt::Formatter<'_>| {
+            let pretty = Pretty::new(|list: &[&str; 2], emit| {
+                let source = |emit: &mut dyn FnMut(&Doc<'_>) -> ::core::fmt::Result| {
+                    list.iter()
+                        .try_for_each(|s| emit(&Doc::Concat(&[Doc::Text(s), Doc::Line])))
+                };
+                let body = [Doc::Items(&source), Doc::Text("end")];
+                emit(&Doc::Group(&Doc::Concat(&body)))
+            });
+            pretty.with_width(9).fmt(&list, f)
+        });
+        assert_eq!(format!("{}", list_doc), "ab cd end");
+        assert_eq!(
+            format!(
+                "{}",
+                list.fmt_by(PrettyList::new().with_width(7).with_indent(1))
+            ),
+            "[\n ab,\n cd,\n]"
+        );
+
+        // 测试空列表、括号与元素处理器
+        let empty: [i32; 0] = [];
+        assert_eq!(format!("{}", empty.fmt_by(PrettyList::new())), "[]");
+        let list = PrettyList::new()
+            .with_brackets("{", "}")
+            .with_separator(";")
+            .with_handler(DebugProxy);
+        assert_eq!(format!("{}", ["a", "b"].fmt_by(list)), r#"{"a"; "b"}"#);
+        assert_eq!(
+            format!("{:6}", ["a", "b"].fmt_by(list)),
+            "{\n    \"a\";\n    \"b\";\n}"
+        );
+    }
+
+    #[test]
+    fn test_pretty_map() {
+        let map = std::collections::BTreeMap::from([(1, "one"), (2, "two")]);
+        assert_eq!(
+            format!("{}", map.fmt_by(PrettyMap::new())),
+            r#"{1: "one", 2: "two"}"#
+        );
+        // 测试换行与嵌套在外层文本中的宽度
+        let map_doc = map.fmt_by(PrettyMap::new().with_indent(2));
+        assert_eq!(
+            format!("{:19}", map_doc),
+            "{\n  1: \"one\",\n  2: \"two\",\n}"
+        );
+        assert_eq!(format!("{:20}", map_doc), r#"{1: "one", 2: "two"}"#);
+
+        // 测试空映射、分隔符与处理器
+        let empty = std::collections::BTreeMap::<i32, i32>::new();
+        assert_eq!(format!("{}", empty.fmt_by(PrettyMap::new())), "{}");
+        let map_doc = map.fmt_by(
+            PrettyMap::new()
+                .with_brackets("(", ")")
+                .with_separator(";")
+                .with_key_separator(" => ")
+                .with_handler(DisplayProxy)
+                .with_width(10),
+        );
+        assert_eq!(format!("{}", map_doc), "(\n    1 => one;\n    2 => two;\n)");
+    }
+}
@@ -0,0 +1,441 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::FmtHandler;
+
+/// 可显示为进度的类型，见 [`ProgressBar`] 与 [`Gauge`].
+///
+/// 已为 [`f32`]、[`f64`]（完成比例）及整数的 `(done, total)` 对实现。
+pub trait Progress {
+    /// 完成比例，超出 `0.0..=1.0` 的部分由调用方截断。
+    fn fraction(&self) -> f64;
+}
+
+impl<P: Progress + ?::core::marker::Sized> Progress for &P {
+    #[inline]
+    fn fraction(&self) -> f64 {
+        (**self).fraction()
+    }
+}
+
+impl Progress for f64 {
+    #[inline]
+    fn fraction(&self) -> f64 {
+        *self
+    }
+}
+
+impl Progress for f32 {
+    #[inline]
+    fn fraction(&self) -> f64 {
+        *self as f64
+    }
+}
+
+macro_rules! impl_progress_for_pair {
+    ($($t: ty),+) => {
+        $(
+            /// `total` 为 `0` 时视为已完成。
+            impl Progress for ($t, $t) {
+                #[inline]
+                fn fraction(&self) -> f64 {
+                    match self.1 {
+                        0 => 1.0,
+                        total => self.0 as f64 / total as f64,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_progress_for_pair!(u8, u16, u32, u64, u128, usize);
+
+/// 将完成比例截断至 `0.0..=1.0`, NaN 视为 `0.0`.
+#[inline]
+fn clamp_fraction(fraction: f64) -> f64 {
+    if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, 1.0)
+    }
+}
+
+/// 将 `fraction` 换算为 `count` 个单元格、每格 `steps` 级中的已填充级数，向下取整。
+///
+/// 未完成时不会填满，完成时总是填满。
+fn filled_units(fraction: f64, count: usize, steps: usize) -> usize {
+    let total = count * steps;
+    if fraction >= 1.0 {
+        return total;
+    }
+    // 容许浮点误差，使 0.3 等比例恰好落在对应的格上
+    let units = (fraction * total as f64 + 1e-9) as usize;
+    units.min(total.saturating_sub(1))
+}
+
+/// 已填充 `units` 级时第 `index` 个单元格的字符。
+#[inline]
+fn cell(units: usize, index: usize, filled: char, empty: char, partials: &[char]) -> char {
+    let steps = partials.len() + 1;
+    match units.saturating_sub(index * steps) {
+        0 => empty,
+        level if level >= steps => filled,
+        level => partials[level - 1],
+    }
+}
+
+/// 以 `h:mm:ss` 格式输出剩余时间，右对齐至 9 列，不足一秒的部分舍去。
+///
+/// 无法估计时输出 `--:--:--`, 超过 `99:59:59` 时输出 `>99:59:59`.
+fn write_eta(
+    f: &mut ::core::fmt::Formatter<'_>,
+    remaining: Option<::core::time::Duration>,
+) -> ::core::fmt::Result {
+    const MAX_SECS: u64 = 100 * 3600 - 1;
+    match remaining.map(|remaining| remaining.as_secs()) {
+        None => f.write_str(" --:--:--"),
+        Some(secs) if secs > MAX_SECS => f.write_str(">99:59:59"),
+        Some(secs) => {
+            let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+            write!(f, "{:>3}:{:02}:{:02}", hours, minutes, seconds)
+        }
+    }
+}
+
+/// 用于将完成比例格式化为水平进度条的格式化处理器
+///
+/// 进度条共 `width` 格，每格按 `partials` 细分，默认以八分之一方块字符（`▏` 至 `▉`）显示不足一格的部分。
+/// 可选地在其后输出百分比，以及根据已用时间估计的剩余时间（ETA）。
+///
+/// 不分配内存，适合频繁重绘；百分比与剩余时间均右对齐至固定宽度，因而输出宽度在进度变化时保持不变。
+///
+/// # 字段
+/// - `width`: 进度条的格数，默认为 `20`.
+/// - `filled`: 已填满的格，默认为 `█`.
+/// - `empty`: 未填充的格，默认为空格。
+/// - `partials`: 由少到多排列的部分填充字符，为空时不细分，默认为 [`ProgressBar::EIGHTHS`].
+/// - `percent`: 是否输出百分比，默认为 `false`.
+/// - `elapsed`: 已用时间，设置后输出剩余时间的估计。
+///
+/// # 示例
+/// ``` rust
+/// use core::time::Duration;
+/// use wfu::{FmtBy, ProgressBar};
+/// let bar = ProgressBar::new().with_width(10);
+/// assert_eq!(format!("[{}]", 0.45.fmt_by(bar)), "[████▌     ]");
+/// let bar = bar.with_percent(true).with_elapsed(Duration::from_secs(30));
+/// assert_eq!(
+///     format!("{}", (25u32, 100u32).fmt_by(bar)),
+///     "██▌         25% ETA   0:01:30"
+/// );
+/// let ascii = ProgressBar::ascii().with_width(8);
+/// assert_eq!(format!("[{}]", (3u8, 4u8).fmt_by(ascii)), "[######..]");
+/// ```
+/// # 注意
+/// - 比例截断至 `0.0..=1.0`, NaN 视为 `0.0`; 未完成时进度条与百分比都不会显示为已满。
+/// - 剩余时间以 `h:mm:ss` 格式输出；进度为 `0` 时无法估计，输出 `--:--:--`; 超过 100 小时时输出 `>99:59:59`.
+#[derive(Debug, Clone, Copy)]
+pub struct ProgressBar<'a> {
+    pub width: usize,
+    pub filled: char,
+    pub empty: char,
+    pub partials: &'a [char],
+    pub percent: bool,
+    pub elapsed: Option<::core::time::Duration>,
+}
+
+impl ProgressBar<'_> {
+    /// 由少到多的八分之一方块字符。
+    pub const EIGHTHS: &'static [char] = &['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+    /// 宽 20 格，以 `█` 与八分之一方块字符填充。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            width: 20,
+            filled: '█',
+            empty: ' ',
+            partials: Self::EIGHTHS,
+            percent: false,
+            elapsed: None,
+        }
+    }
+    /// 宽 20 格，以 `#` 与 `.` 填充，不细分。
+    #[inline]
+    pub const fn ascii() -> Self {
+        Self {
+            filled: '#',
+            empty: '.',
+            partials: &[],
+            ..Self::new()
+        }
+    }
+}
+
+impl ::core::default::Default for ProgressBar<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ProgressBar<'a> {
+    /// 替换进度条的格数。
+    #[inline]
+    pub fn with_width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+    /// 替换已填满的格。
+    #[inline]
+    pub fn with_filled(self, filled: char) -> Self {
+        Self { filled, ..self }
+    }
+    /// 替换未填充的格。
+    #[inline]
+    pub fn with_empty(self, empty: char) -> Self {
+        Self { empty, ..self }
+    }
+    /// 替换部分填充字符。
+    #[inline]
+    pub fn with_partials(self, partials: &'a [char]) -> Self {
+        Self { partials, ..self }
+    }
+    /// 设置是否输出百分比。
+    #[inline]
+    pub fn with_percent(self, percent: bool) -> Self {
+        Self { percent, ..self }
+    }
+    /// 设置已用时间，以输出剩余时间的估计。
+    #[inline]
+    pub fn with_elapsed(self, elapsed: ::core::time::Duration) -> Self {
+        Self {
+            elapsed: Some(elapsed),
+            ..self
+        }
+    }
+}
+
+impl<P> FmtHandler<P> for ProgressBar<'_>
+where
+    P: Progress + ?::core::marker::Sized,
+{
+    fn fmt(&self, data: &P, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        let fraction = clamp_fraction(data.fraction());
+        let units = filled_units(fraction, self.width, self.partials.len() + 1);
+        (0..self.width).try_for_each(|index| {
+            f.write_char(cell(units, index, self.filled, self.empty, self.partials))
+        })?;
+        if self.percent {
+            write!(f, " {:>3}%", filled_units(fraction, 100, 1))?;
+        }
+        if let Some(elapsed) = self.elapsed {
+            f.write_str(" ETA ")?;
+            let remaining = (fraction != 0.0).then(|| {
+                let remaining = elapsed.as_secs_f64() * (1.0 - fraction) / fraction;
+                ::core::time::Duration::try_from_secs_f64(remaining)
+                    .unwrap_or(::core::time::Duration::MAX)
+            });
+            write_eta(f, remaining)?;
+        }
+        Ok(())
+    }
+}
+
+/// 用于将完成比例格式化为竖直量表的格式化处理器
+///
+/// 量表共 `height` 行，自下而上填充，每行按 `partials` 细分，默认以八分之一方块字符（`▁` 至 `▇`）
+/// 显示不足一行的部分。各行宽 `width` 列，以换行符分隔，末行之后不输出换行符。
+///
+/// 不分配内存。`height` 为 `1` 时，输出单个字符，可用于拼接迷你图。
+///
+/// # 字段
+/// - `height`: 行数，默认为 `8`.
+/// - `width`: 每行重复的列数，默认为 `1`.
+/// - `filled`: 已填满的行，默认为 `█`.
+/// - `empty`: 未填充的行，默认为空格。
+/// - `partials`: 由少到多排列的部分填充字符，为空时不细分，默认为 [`Gauge::EIGHTHS`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, Gauge};
+/// let gauge = Gauge::new().with_height(3).with_width(2);
+/// assert_eq!(format!("{}", 0.5.fmt_by(gauge)), "  \n▄▄\n██");
+/// let level = Gauge::new().with_height(1);
+/// let spark: String = [0.1, 0.5, 1.0]
+///     .iter()
+///     .map(|v| v.fmt_by(level).to_string())
+///     .collect();
+/// assert_eq!(spark, " ▄█");
+/// ```
+/// # 注意
+/// - 比例截断至 `0.0..=1.0`, NaN 视为 `0.0`; 未完成时量表不会显示为已满。
+#[derive(Debug, Clone, Copy)]
+pub struct Gauge<'a> {
+    pub height: usize,
+    pub width: usize,
+    pub filled: char,
+    pub empty: char,
+    pub partials: &'a [char],
+}
+
+impl Gauge<'_> {
+    /// 由少到多的八分之一方块字符。
+    pub const EIGHTHS: &'static [char] = &['▁', '▂', '▃', '▄', '▅', '▆', '▇'];
+    /// 高 8 行、宽 1 列，以 `█` 与八分之一方块字符填充。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            height: 8,
+            width: 1,
+            filled: '█',
+            empty: ' ',
+            partials: Self::EIGHTHS,
+        }
+    }
+}
+
+impl ::core::default::Default for Gauge<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Gauge<'a> {
+    /// 替换行数。
+    #[inline]
+    pub fn with_height(self, height: usize) -> Self {
+        Self { height, ..self }
+    }
+    /// 替换每行的列数。
+    #[inline]
+    pub fn with_width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+    /// 替换已填满的行。
+    #[inline]
+    pub fn with_filled(self, filled: char) -> Self {
+        Self { filled, ..self }
+    }
+    /// 替换未填充的行。
+    #[inline]
+    pub fn with_empty(self, empty: char) -> Self {
+        Self { empty, ..self }
+    }
+    /// 替换部分填充字符。
+    #[inline]
+    pub fn with_partials(self, partials: &'a [char]) -> Self {
+        Self { partials, ..self }
+    }
+}
+
+impl<P> FmtHandler<P> for Gauge<'_>
+where
+    P: Progress + ?::core::marker::Sized,
+{
+    fn fmt(&self, data: &P, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        let fraction = clamp_fraction(data.fraction());
+        let units = filled_units(fraction, self.height, self.partials.len() + 1);
+        for row in 0..self.height {
+            if row != 0 {
+                f.write_char('\n')?;
+            }
+            let c = cell(
+                units,
+                self.height - 1 - row,
+                self.filled,
+                self.empty,
+                self.partials,
+            );
+            (0..self.width).try_for_each(|_| f.write_char(c))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FmtBy;
+    use ::core::time::Duration;
+
+    #[test]
+    fn test_progress_bar() {
+        // 测试细分、边界与截断
+        let bar = ProgressBar::new().with_width(4);
+        let render = |fraction: f64| format!("{}", fraction.fmt_by(bar));
+        assert_eq!(render(0.0), "    ");
+        assert_eq!(render(1.0 / 32.0), "▏   ");
+        assert_eq!(render(0.3), "█▏  ");
+        assert_eq!(render(0.999), "███▉");
+        assert_eq!(render(1.0), "████");
+        assert_eq!(render(-1.0), "    ");
+        assert_eq!(render(2.0), "████");
+        assert_eq!(render(f64::NAN), "    ");
+        assert_eq!(format!("{}", (0u64, 0u64).fmt_by(bar)), "████");
+
+        // 测试百分比与剩余时间
+        let bar = ProgressBar::ascii()
+            .with_width(5)
+            .with_percent(true)
+            .with_elapsed(Duration::from_secs(3600));
+        assert_eq!(
+            format!("{}", (1usize, 3usize).fmt_by(bar)),
+            "#....  33% ETA   2:00:00"
+        );
+        assert_eq!(
+            format!("{}", 0.0f32.fmt_by(bar)),
+            ".....   0% ETA  --:--:--"
+        );
+        assert_eq!(
+            format!("{}", (199u8, 200u8).fmt_by(bar)),
+            "####.  99% ETA   0:00:18"
+        );
+        assert_eq!(format!("{}", 1.0.fmt_by(bar)), "##### 100% ETA   0:00:00");
+        let bar = bar.with_filled('=').with_empty(' ').with_partials(&['-']);
+        assert_eq!(format!("{}", 0.5.fmt_by(bar)), "==-    50% ETA   1:00:00");
+
+        // 测试剩余时间的上限与固定宽度
+        assert_eq!(format!("{}", 1e-20.fmt_by(bar)), "        0% ETA >99:59:59");
+        let widths = [0.0, 1e-9, 0.001, 0.3, 0.97, 1.0]
+            .map(|fraction| format!("{}", fraction.fmt_by(bar)).chars().count());
+        assert!(widths.iter().all(|&width| width == widths[0]));
+    }
+
+    #[test]
+    fn test_gauge() {
+        // 测试自下而上填充
+        let gauge = Gauge::new().with_height(2);
+        assert_eq!(format!("{}", 0.0.fmt_by(gauge)), " \n ");
+        assert_eq!(format!("{}", 0.75.fmt_by(gauge)), "▄\n█");
+        assert_eq!(format!("{}", (7u16, 8u16).fmt_by(gauge)), "▆\n█");
+        let gauge = gauge
+            .with_width(3)
+            .with_filled('#')
+            .with_empty('.')
+            .with_partials(&[]);
+        assert_eq!(format!("{}", 0.6.fmt_by(gauge)), "...\n###");
+        assert_eq!(format!("{}", 0.0.fmt_by(gauge.with_height(0))), "");
+    }
+}
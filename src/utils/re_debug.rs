@@ -0,0 +1,976 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DebugColors, DebugProxy, FmtHandler, FmtOptions, FmtTrait, TextFilter, str_width};
+
+/// [`Debug`](::core::fmt::Debug) 输出中的记号，见 [`DebugParser`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DebugToken<'s> {
+    /// 原子（标识符、路径、字面量等）的一段文本。相邻的 `Text` 属于同一个原子；
+    /// 字符串与字符字面量中的括号、逗号与冒号都属于文本。
+    Text(&'s str),
+    /// 两个原子之间的空白。
+    Space,
+    /// `{`、`(` 或 `[`.
+    Open(char),
+    /// `}`、`)` 或 `]`.
+    Close(char),
+    Comma,
+    /// 字段名或映射的键之后的 `:`; 路径中的 `::` 属于文本。
+    Colon,
+}
+
+/// 接收 [`DebugParser`] 解析出的记号。
+///
+/// 已为形如 `FnMut(&mut Formatter, DebugToken) -> fmt::Result` 的闭包实现。
+pub trait DebugVisitor {
+    fn token(
+        &mut self,
+        out: &mut ::core::fmt::Formatter<'_>,
+        token: DebugToken<'_>,
+    ) -> ::core::fmt::Result;
+    #[inline]
+    fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let _ = out;
+        Ok(())
+    }
+}
+
+impl<F> DebugVisitor for F
+where
+    F: FnMut(&mut ::core::fmt::Formatter<'_>, DebugToken<'_>) -> ::core::fmt::Result,
+{
+    #[inline]
+    fn token(
+        &mut self,
+        out: &mut ::core::fmt::Formatter<'_>,
+        token: DebugToken<'_>,
+    ) -> ::core::fmt::Result {
+        self(out, token)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParseState {
+    Normal,
+    /// 字符串字面量中，`true` 表示刚读到反斜杠。
+    Str(bool),
+    /// 刚读到 `'`.
+    CharStart,
+    /// 读到 `'` 与一个字符，下一个字符不是 `'` 时不是字符字面量（如生命周期）。
+    CharBody,
+    /// 字符字面量中刚读到反斜杠。
+    CharEscape,
+    /// 字符字面量中转义序列的其余部分，直至 `'`.
+    CharRest,
+}
+
+/// 流式解析派生的 [`Debug`](::core::fmt::Debug) 输出的文本过滤器
+///
+/// 将输出拆分为 [`DebugToken`] 并依次交给 `visitor`, 不分配内存。文本可分多次写入，记号可跨越写入的边界。
+/// 结构体、元组、列表、映射与字面量都由括号、逗号、冒号与原子构成，解析器只识别这些记号，不校验其嵌套是否合法。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{DebugParser, DebugToken, DebugVisitor, FmtTrait, FmtWith, fmt_filtered};
+/// #[derive(Debug)]
+/// struct Point {
+///     x: i32,
+///     label: &'static str,
+/// }
+/// // 统计字段数
+/// let point = Point { x: 1, label: "a: b" };
+/// let proxy = point.fmt_with(&|point, f| {
+///     let mut fields = 0;
+///     let counter = |_: &mut std::fmt::Formatter<'_>, token: DebugToken<'_>| {
+///         fields += (token == DebugToken::Colon) as usize;
+///         Ok(())
+///     };
+///     fmt_filtered(DebugParser::new(counter), point, wfu::DebugProxy, f, FmtTrait::Debug)?;
+///     write!(f, "{} fields", fields)
+/// });
+/// assert_eq!(format!("{}", proxy), "2 fields");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DebugParser<Visitor> {
+    visitor: Visitor,
+    state: ParseState,
+    /// 读到一个 `:`, 尚不知是否为 `::`.
+    colon: bool,
+    /// 读到原子之后的空白，尚不知其后是否还有原子。
+    space: bool,
+    /// 上一个记号是否为文本。
+    after_text: bool,
+}
+
+impl<Visitor> DebugParser<Visitor> {
+    #[inline]
+    pub const fn new(visitor: Visitor) -> Self {
+        Self {
+            visitor,
+            state: ParseState::Normal,
+            colon: false,
+            space: false,
+            after_text: false,
+        }
+    }
+    #[inline]
+    pub fn into_inner(self) -> Visitor {
+        self.visitor
+    }
+}
+
+impl<Visitor: DebugVisitor> DebugParser<Visitor> {
+    fn emit_text(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        if ::core::mem::take(&mut self.space) {
+            self.visitor.token(out, DebugToken::Space)?;
+        }
+        self.after_text = true;
+        self.visitor.token(out, DebugToken::Text(s))
+    }
+    fn emit(
+        &mut self,
+        out: &mut ::core::fmt::Formatter<'_>,
+        token: DebugToken<'_>,
+    ) -> ::core::fmt::Result {
+        self.space = false;
+        self.after_text = false;
+        self.visitor.token(out, token)
+    }
+    /// 输出暂存的 `:`.
+    fn flush_colon(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        if ::core::mem::take(&mut self.colon) {
+            self.emit(out, DebugToken::Colon)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Visitor: DebugVisitor> TextFilter for DebugParser<Visitor> {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        // 当前文本段的起点
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            match self.state {
+                ParseState::Str(escaped) => {
+                    self.state = match c {
+                        _ if escaped => ParseState::Str(false),
+                        '\\' => ParseState::Str(true),
+                        '"' => ParseState::Normal,
+                        _ => ParseState::Str(false),
+                    };
+                    continue;
+                }
+                ParseState::CharStart => {
+                    self.state = match c {
+                        '\\' => ParseState::CharEscape,
+                        _ => ParseState::CharBody,
+                    };
+                    continue;
+                }
+                ParseState::CharEscape => {
+                    self.state = ParseState::CharRest;
+                    continue;
+                }
+                ParseState::CharRest => {
+                    if c == '\'' {
+                        self.state = ParseState::Normal;
+                    }
+                    continue;
+                }
+                ParseState::CharBody => {
+                    self.state = ParseState::Normal;
+                    if c == '\'' {
+                        continue;
+                    }
+                }
+                ParseState::Normal => {}
+            }
+            if self.colon {
+                if c == ':' {
+                    self.colon = false;
+                    self.emit_text(out, "::")?;
+                    start = i + 1;
+                    continue;
+                }
+                self.flush_colon(out)?;
+            }
+            let token = match c {
+                '{' | '(' | '[' => Some(DebugToken::Open(c)),
+                '}' | ')' | ']' => Some(DebugToken::Close(c)),
+                ',' => Some(DebugToken::Comma),
+                ':' => None,
+                _ if c.is_whitespace() => None,
+                '"' => {
+                    self.state = ParseState::Str(false);
+                    continue;
+                }
+                '\'' => {
+                    self.state = ParseState::CharStart;
+                    continue;
+                }
+                _ => continue,
+            };
+            self.emit_text(out, &s[start..i])?;
+            start = i + c.len_utf8();
+            match token {
+                Some(token) => self.emit(out, token)?,
+                None if c == ':' => self.colon = true,
+                None => self.space = self.after_text,
+            }
+        }
+        self.emit_text(out, &s[start..])
+    }
+    #[inline]
+    fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.flush_colon(out)?;
+        self.visitor.finish(out)
+    }
+}
+
+/// 重新排版的方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DebugLayout {
+    /// 总在一行。
+    Compact,
+    /// 每个元素一行，与 `{:#?}` 相同。
+    Expanded,
+    /// 放得下时在一行，否则每个元素一行。
+    Width(usize),
+}
+
+/// 一层括号。
+#[derive(Debug, Clone, Copy, Default)]
+struct Frame {
+    close: char,
+    /// 结构体的花括号，括号内侧有空格。
+    spaced: bool,
+    flat: bool,
+    entries: usize,
+    /// 当前元素是否已开始输出。
+    in_entry: bool,
+}
+
+/// 最多记录的括号层数，更深的内容被省略。
+const MAX_FRAMES: usize = 32;
+/// 暂存字段名的最大字节数，更长的字段名不会被隐藏。
+const MAX_NAME: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Skip {
+    None,
+    /// 跳过超出深度的括号中的内容，记录其中嵌套的层数。
+    Elided(usize),
+    /// 跳过被隐藏的字段，记录其中嵌套的层数。
+    Hidden(usize),
+}
+
+/// 测量第 `target` 个括号在一行中的宽度。
+#[derive(Debug, Clone, Copy)]
+struct Measure {
+    target: usize,
+    limit: usize,
+    /// 开始测量时的层数与已测得的宽度。
+    counting: Option<(usize, usize)>,
+    /// 测量结果：是否放得下。
+    fits: Option<bool>,
+}
+
+/// 以另一个 [`TextFilter`] 重新格式化内部处理器的输出，供测量使用。
+type Source<'s> =
+    &'s dyn Fn(&mut dyn TextFilter, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result;
+
+struct Renderer<'r> {
+    layout: DebugLayout,
+    max_depth: Option<usize>,
+    hidden: &'r [&'r str],
+    indent: usize,
+    source: Option<Source<'r>>,
+    frames: [Frame; MAX_FRAMES],
+    depth: usize,
+    skip: Skip,
+    name: [u8; MAX_NAME],
+    /// 正在暂存的字段名长度。
+    naming: Option<usize>,
+    after_text: bool,
+    /// 上一个记号是否为文本，即下一段文本是否属于同一个原子。
+    in_atom: bool,
+    column: usize,
+    opens: usize,
+    measure: Option<Measure>,
+    colors: Option<DebugColors<'r>>,
+    /// 是否已输出颜色而尚未复原。
+    colored: bool,
+}
+
+impl<'r> Renderer<'r> {
+    fn new<H>(re_debug: &ReDebug<'r, H>, layout: DebugLayout, source: Source<'r>) -> Self {
+        Self {
+            layout,
+            max_depth: re_debug.max_depth,
+            hidden: re_debug.hidden,
+            indent: re_debug.indent,
+            source: Some(source),
+            frames: [Frame::default(); MAX_FRAMES],
+            depth: 0,
+            skip: Skip::None,
+            name: [0; MAX_NAME],
+            naming: None,
+            after_text: false,
+            in_atom: false,
+            column: 0,
+            opens: 0,
+            measure: None,
+            colors: None,
+            colored: false,
+        }
+    }
+    fn put(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        if let Some(measure) = &mut self.measure {
+            if let Some((_, width)) = &mut measure.counting {
+                *width += str_width(s);
+                if *width > measure.limit {
+                    measure.fits = Some(false);
+                    return Err(::core::fmt::Error);
+                }
+            }
+            return Ok(());
+        }
+        self.column += str_width(s);
+        out.write_str(s)
+    }
+    /// 以 `sgr` 指定的颜色开始输出。
+    fn start_color(
+        &mut self,
+        out: &mut ::core::fmt::Formatter<'_>,
+        sgr: &str,
+    ) -> ::core::fmt::Result {
+        if sgr.is_empty() || self.measure.is_some() {
+            return Ok(());
+        }
+        self.colored = true;
+        write!(out, "\x1b[{}m", sgr)
+    }
+    fn end_color(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match ::core::mem::take(&mut self.colored) {
+            true => out.write_str("\x1b[0m"),
+            false => Ok(()),
+        }
+    }
+    /// 输出标点。
+    fn punct(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        let sgr = self.colors.map_or("", |colors| colors.punct);
+        self.start_color(out, sgr)?;
+        self.put(out, s)?;
+        self.end_color(out)
+    }
+    #[inline]
+    fn punct_char(&mut self, out: &mut ::core::fmt::Formatter<'_>, c: char) -> ::core::fmt::Result {
+        self.punct(out, c.encode_utf8(&mut [0; 4]))
+    }
+    fn newline(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let indent = self.indent * self.depth;
+        self.column = indent;
+        write!(out, "\n{:indent$}", "")
+    }
+    #[inline]
+    fn top(&mut self) -> Option<&mut Frame> {
+        self.frames[..self.depth].last_mut()
+    }
+    fn begin_entry(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Some(frame) = self.top() else {
+            return Ok(());
+        };
+        if frame.in_entry {
+            return Ok(());
+        }
+        let frame = ::core::mem::replace(
+            frame,
+            Frame {
+                in_entry: true,
+                entries: frame.entries + 1,
+                ..*frame
+            },
+        );
+        match (frame.flat, frame.entries, frame.spaced) {
+            (false, _, _) => self.newline(out),
+            (true, 0, true) => self.put(out, " "),
+            (true, 0, false) => Ok(()),
+            (true, _, _) => {
+                self.punct(out, ",")?;
+                self.put(out, " ")
+            }
+        }
+    }
+    fn end_entry(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Some(frame) = self.top() else {
+            return Ok(());
+        };
+        let flat = frame.flat;
+        if ::core::mem::take(&mut frame.in_entry) && !flat {
+            self.punct(out, ",")?;
+        }
+        Ok(())
+    }
+    /// 输出暂存的标识符，并按其后的记号 `next` 着色。
+    fn flush_name(
+        &mut self,
+        out: &mut ::core::fmt::Formatter<'_>,
+        next: Option<DebugToken<'_>>,
+    ) -> ::core::fmt::Result {
+        let Some(len) = self.naming.take() else {
+            return Ok(());
+        };
+        let name = self.name;
+        let name = ::core::str::from_utf8(&name[..len]).map_err(|_| ::core::fmt::Error)?;
+        self.begin_entry(out)?;
+        // 其后为括号的是结构体或变体名，其后为逗号、闭括号或结尾的是单元结构体或单元变体名
+        let sgr = match (self.colors, next) {
+            (Some(colors), Some(DebugToken::Colon)) => colors.field,
+            (Some(_), _) if matches!(name, "true" | "false") => "",
+            (
+                Some(colors),
+                Some(DebugToken::Open(_) | DebugToken::Comma | DebugToken::Close(_)),
+            )
+            | (Some(colors), None) => colors.name,
+            _ => "",
+        };
+        self.start_color(out, sgr)?;
+        self.put(out, name)?;
+        self.end_color(out)
+    }
+    /// 判断第 `index` 个括号能否放在当前行。
+    fn fits(&self, out: &mut ::core::fmt::Formatter<'_>, index: usize, width: usize) -> bool {
+        let Some(source) = self.source else {
+            return true;
+        };
+        // 换行的上一层在元素后还有一个逗号
+        let comma = self.frames[..self.depth]
+            .last()
+            .is_some_and(|frame| !frame.flat) as usize;
+        let Some(limit) = width.checked_sub(self.column + comma) else {
+            return false;
+        };
+        // 以相同的设置在一行中重新输出，不产生输出
+        let mut parser = DebugParser::new(Renderer {
+            layout: DebugLayout::Compact,
+            source: None,
+            frames: [Frame::default(); MAX_FRAMES],
+            depth: 0,
+            skip: Skip::None,
+            naming: None,
+            after_text: false,
+            in_atom: false,
+            column: 0,
+            opens: 0,
+            colors: None,
+            colored: false,
+            measure: Some(Measure {
+                target: index,
+                limit,
+                counting: None,
+                fits: None,
+            }),
+            ..*self
+        });
+        let _ = source(&mut parser, out);
+        // 未得出结论时，括号未闭合
+        parser
+            .into_inner()
+            .measure
+            .is_some_and(|measure| measure.fits == Some(true))
+    }
+    fn open(&mut self, out: &mut ::core::fmt::Formatter<'_>, c: char) -> ::core::fmt::Result {
+        self.begin_entry(out)?;
+        let spaced = c == '{' && ::core::mem::take(&mut self.after_text);
+        let close = match c {
+            '{' => '}',
+            '(' => ')',
+            _ => ']',
+        };
+        let index = self.opens;
+        self.opens += 1;
+        if let Some(measure) = &mut self.measure
+            && measure.target == index
+        {
+            measure.counting = Some((self.depth, 0));
+        }
+        if self.depth == MAX_FRAMES || self.max_depth.is_some_and(|max| self.depth >= max) {
+            self.skip = Skip::Elided(0);
+            if spaced {
+                self.put(out, " ")?;
+                return self.punct(out, "{ .. }");
+            }
+            self.punct_char(out, c)?;
+            self.punct(out, "..")?;
+            return self.punct_char(out, close);
+        }
+        let parent_flat = self.frames[..self.depth]
+            .last()
+            .is_some_and(|frame| frame.flat);
+        let flat = match self.layout {
+            DebugLayout::Compact => true,
+            DebugLayout::Expanded => false,
+            DebugLayout::Width(width) => parent_flat || self.fits(out, index, width),
+        };
+        if spaced {
+            self.put(out, " ")?;
+        }
+        self.punct_char(out, c)?;
+        self.frames[self.depth] = Frame {
+            close,
+            spaced,
+            flat,
+            entries: 0,
+            in_entry: false,
+        };
+        self.depth += 1;
+        Ok(())
+    }
+    fn close(&mut self, out: &mut ::core::fmt::Formatter<'_>, c: char) -> ::core::fmt::Result {
+        if self.depth == 0 {
+            return self.punct_char(out, c);
+        }
+        self.end_entry(out)?;
+        self.depth -= 1;
+        let frame = self.frames[self.depth];
+        if !frame.flat && frame.entries > 0 {
+            self.newline(out)?;
+        } else if frame.spaced && frame.entries > 0 {
+            self.put(out, " ")?;
+        }
+        self.punct_char(out, frame.close)?;
+        if let Some(measure) = &mut self.measure
+            && let Some((depth, _)) = measure.counting
+            && depth == self.depth
+        {
+            // 测量完毕，中止格式化
+            measure.fits = Some(true);
+            return Err(::core::fmt::Error);
+        }
+        Ok(())
+    }
+}
+
+impl DebugVisitor for Renderer<'_> {
+    fn token(
+        &mut self,
+        out: &mut ::core::fmt::Formatter<'_>,
+        token: DebugToken<'_>,
+    ) -> ::core::fmt::Result {
+        match (self.skip, token) {
+            (Skip::None, _) => {}
+            (Skip::Elided(n) | Skip::Hidden(n), DebugToken::Open(_)) => {
+                self.skip = match self.skip {
+                    Skip::Elided(_) => Skip::Elided(n + 1),
+                    _ => Skip::Hidden(n + 1),
+                };
+                return Ok(());
+            }
+            (Skip::Elided(0), DebugToken::Close(_)) => {
+                self.skip = Skip::None;
+                return Ok(());
+            }
+            (Skip::Hidden(0), DebugToken::Close(_)) => self.skip = Skip::None,
+            (Skip::Hidden(0), DebugToken::Comma) => {
+                self.skip = Skip::None;
+                return Ok(());
+            }
+            (Skip::Elided(n), DebugToken::Close(_)) => {
+                self.skip = Skip::Elided(n - 1);
+                return Ok(());
+            }
+            (Skip::Hidden(n), DebugToken::Close(_)) => {
+                self.skip = Skip::Hidden(n - 1);
+                return Ok(());
+            }
+            _ => return Ok(()),
+        }
+        let in_atom = ::core::mem::replace(&mut self.in_atom, matches!(token, DebugToken::Text(_)));
+        if !self.in_atom {
+            self.end_color(out)?;
+        }
+        if let Some(len) = self.naming {
+            match token {
+                DebugToken::Text(s) if len + s.len() <= MAX_NAME => {
+                    self.name[len..len + s.len()].copy_from_slice(s.as_bytes());
+                    self.naming = Some(len + s.len());
+                    return Ok(());
+                }
+                DebugToken::Colon
+                    if ::core::str::from_utf8(&self.name[..len])
+                        .is_ok_and(|name| self.hidden.contains(&name)) =>
+                {
+                    self.naming = None;
+                    self.skip = Skip::Hidden(0);
+                    return Ok(());
+                }
+                _ => self.flush_name(out, Some(token))?,
+            }
+        }
+        match token {
+            DebugToken::Text(s) if in_atom => self.put(out, s),
+            DebugToken::Text(s) => {
+                let starts_field = self.frames[..self.depth]
+                    .last()
+                    .is_some_and(|frame| frame.spaced && !frame.in_entry);
+                let first = s.chars().next().unwrap_or_default();
+                let ident = first.is_alphabetic() || first == '_';
+                self.after_text = true;
+                // 字段名须待其后的记号确定是否隐藏；着色时标识符须待其后的记号确定颜色
+                let hidable = starts_field && !self.hidden.is_empty();
+                if (hidable || ident && self.colors.is_some()) && s.len() <= MAX_NAME {
+                    self.name[..s.len()].copy_from_slice(s.as_bytes());
+                    self.naming = Some(s.len());
+                    return Ok(());
+                }
+                self.begin_entry(out)?;
+                let sgr = match self.colors {
+                    Some(colors) if first == '"' || first == '\'' => colors.string,
+                    Some(colors) if first.is_ascii_digit() || first == '-' => colors.number,
+                    _ => "",
+                };
+                self.start_color(out, sgr)?;
+                self.put(out, s)
+            }
+            DebugToken::Space => self.put(out, " "),
+            DebugToken::Open(c) => self.open(out, c),
+            DebugToken::Close(c) => {
+                self.after_text = false;
+                self.close(out, c)
+            }
+            DebugToken::Comma => {
+                self.after_text = false;
+                match self.depth {
+                    0 => {
+                        self.punct(out, ",")?;
+                        self.put(out, " ")
+                    }
+                    _ => self.end_entry(out),
+                }
+            }
+            DebugToken::Colon => {
+                self.after_text = false;
+                self.punct(out, ":")?;
+                self.put(out, " ")
+            }
+        }
+    }
+    #[inline]
+    fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.flush_name(out, None)?;
+        self.end_color(out)
+    }
+}
+
+/// 用于重新排版 [`Debug`](::core::fmt::Debug) 输出的格式化处理器
+///
+/// 以 [`DebugParser`] 解析内部处理器的输出，再按以下方式之一重新输出：
+/// - `{:?}`: 总在一行，即使内部处理器总以多行输出；
+/// - `{:#?}`: 每个元素一行，与派生的 `{:#?}` 相同；
+/// - `{:80?}` 或设置了 `width`: 每层括号放得下时在一行，否则其中每个元素一行。
+///
+/// 此外可以将超出 `max_depth` 层的括号中的内容省略为 `..`, 或隐藏结构体中名为 `hidden` 之一的字段。
+/// 适用于无法修改的第三方 [`Debug`](::core::fmt::Debug) 实现。
+///
+/// # 字段
+/// - `width`: 目标宽度；为 [`None`] 时取自格式化参数中的宽度。
+/// - `max_depth`: 保留的最大括号层数。
+/// - `hidden`: 需隐藏的字段名。
+/// - `indent`: 每层的缩进，默认为 `4`.
+/// - `handler`: 内部处理器，默认为 [`DebugProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, ReDebug};
+/// #[derive(Debug)]
+/// struct User {
+///     name: &'static str,
+///     password: &'static str,
+///     roles: Vec<&'static str>,
+/// }
+/// let user = User { name: "root", password: "123456", roles: vec!["admin", "dev"] };
+/// let re_debug = ReDebug::new().with_hidden(&["password"]);
+/// assert_eq!(
+///     format!("{:?}", user.fmt_by(re_debug)),
+///     r#"User { name: "root", roles: ["admin", "dev"] }"#
+/// );
+/// assert_eq!(
+///     format!("{:30?}", user.fmt_by(re_debug)),
+///     "User {\n    name: \"root\",\n    roles: [\"admin\", \"dev\"],\n}"
+/// );
+/// assert_eq!(
+///     format!("{:?}", user.fmt_by(re_debug.with_max_depth(1))),
+///     r#"User { name: "root", roles: [..] }"#
+/// );
+/// ```
+/// # 注意
+/// - 判断一层括号能否放下时，只计入到其闭括号及随后的逗号为止。
+/// - 启用 `alloc` 特性时，内部处理器的输出先缓冲于 `String`;
+///   否则每次判断都需重新调用内部处理器，耗时与输出长度和括号数之积成正比。
+/// - 最多保留 32 层括号；长于 64 字节的字段名不会被隐藏。
+/// - 不校验输出是否合乎语法；括号不匹配时尽量原样输出。
+#[derive(Debug, Clone, Copy)]
+pub struct ReDebug<'a, Handler = DebugProxy> {
+    pub width: Option<usize>,
+    pub max_depth: Option<usize>,
+    pub hidden: &'a [&'a str],
+    pub indent: usize,
+    pub handler: Handler,
+}
+
+impl ReDebug<'_> {
+    /// 以 [`DebugProxy`] 为内部处理器构造，不限深度、不隐藏字段。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            width: None,
+            max_depth: None,
+            hidden: &[],
+            indent: 4,
+            handler: DebugProxy,
+        }
+    }
+}
+
+impl ::core::default::Default for ReDebug<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Handler> ReDebug<'a, Handler> {
+    /// 设置目标宽度。
+    #[inline]
+    pub fn with_width(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+    /// 设置保留的最大括号层数。
+    #[inline]
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+    /// 设置需隐藏的字段名。
+    #[inline]
+    pub fn with_hidden(self, hidden: &'a [&'a str]) -> Self {
+        Self { hidden, ..self }
+    }
+    /// 替换每层的缩进。
+    #[inline]
+    pub fn with_indent(self, indent: usize) -> Self {
+        Self { indent, ..self }
+    }
+    /// 替换内部处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> ReDebug<'a, NewHandler> {
+        ReDebug {
+            width: self.width,
+            max_depth: self.max_depth,
+            hidden: self.hidden,
+            indent: self.indent,
+            handler,
+        }
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for ReDebug<'_, Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Debug)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        let layout = match (self.width.or(f.width()), f.alternate()) {
+            (Some(width), _) => DebugLayout::Width(width),
+            (None, true) => DebugLayout::Expanded,
+            (None, false) => DebugLayout::Compact,
+        };
+        self.render(data, f, fmt_trait, layout, None)
+    }
+}
+
+impl<Handler> ReDebug<'_, Handler> {
+    /// 以 `layout` 重新排版 `handler` 的输出，`colors` 不为 [`None`] 时着色。
+    pub(crate) fn render<T>(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+        layout: DebugLayout,
+        colors: Option<DebugColors<'_>>,
+    ) -> ::core::fmt::Result
+    where
+        T: ?::core::marker::Sized,
+        Handler: FmtHandler<T>,
+    {
+        // 内部处理器总以紧凑的单行格式输出
+        let options = FmtOptions {
+            alternate: false,
+            ..FmtOptions::of(f)
+        };
+        #[cfg(feature = "alloc")]
+        {
+            let mut buf = ::alloc::string::String::new();
+            options.write_by(&mut buf, data, self.handler, fmt_trait)?;
+            let source = |filter: &mut dyn TextFilter, out: &mut ::core::fmt::Formatter<'_>| {
+                filter.write_str(out, &buf)?;
+                filter.finish(out)
+            };
+            let mut parser = DebugParser::new(Renderer::new(self, layout, &source));
+            parser.visitor.colors = colors;
+            source(&mut parser, f)
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            let source = |filter: &mut dyn TextFilter, out: &mut ::core::fmt::Formatter<'_>| {
+                crate::fmt_filtered_with(filter, data, self.handler, out, options, fmt_trait)
+            };
+            let mut parser = DebugParser::new(Renderer::new(self, layout, &source));
+            parser.visitor.colors = colors;
+            source(&mut parser, f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DisplayProxy, FmtAs, FmtBy, FmtFn, fmt_filtered};
+    use ::core::fmt::Write;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Node {
+        id: u32,
+        tag: Option<char>,
+        children: Vec<Node>,
+    }
+
+    fn sample() -> Node {
+        Node {
+            id: 1,
+            tag: Some(')'),
+            children: vec![
+                Node {
+                    id: 2,
+                    tag: None,
+                    children: vec![],
+                },
+                Node {
+                    id: 3,
+                    tag: Some('\''),
+                    children: vec![],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_debug_parser() {
+        // 将记号输出为简短的形式，并分段写入以测试跨越边界的记号
+        let source = r#"Foo { a: std::b::C("x, (y)"), d: ['\'', 'e'] }"#;
+        let chunks = FmtFn(|f: &mut ::core::fmt::Formatter<'_>| {
+            source
+                .split_inclusive(':')
+                .try_for_each(|chunk| f.write_str(chunk))
+        });
+        let visitor = |out: &mut ::core::fmt::Formatter<'_>, token: DebugToken<'_>| match token {
+            DebugToken::Text(s) => write!(out, "<{}>", s),
+            DebugToken::Space => out.write_str("_"),
+            DebugToken::Open(c) | DebugToken::Close(c) => out.write_char(c),
+            DebugToken::Comma => out.write_str(","),
+            DebugToken::Colon => out.write_str(":"),
+        };
+        let tokens = FmtFn(|f: &mut ::core::fmt::Formatter<'_>| {
+            fmt_filtered(
+                DebugParser::new(visitor),
+                &chunks,
+                DisplayProxy,
+                f,
+                FmtTrait::Display,
+            )
+        });
+        assert_eq!(
+            format!("{}", tokens),
+            r#"<Foo>{<a>:<std><::><b><::><C>(<"x, (y)">),<d>:[<'\''>,<'e'>]}"#
+        );
+    }
+
+    #[test]
+    fn test_re_debug() {
+        let node = sample();
+        let compact = r#"Node { id: 1, tag: Some(')'), children: [Node { id: 2, tag: None, children: [] }, Node { id: 3, tag: Some('\''), children: [] }] }"#;
+        assert_eq!(format!("{:?}", node.fmt_as::<ReDebug>()), compact);
+        // 展开的格式与派生的 `{:#?}` 相同，且可由展开的输出恢复为紧凑格式
+        assert_eq!(
+            format!("{:#?}", node.fmt_as::<ReDebug>()),
+            format!("{:#?}", node)
+        );
+        let pretty = crate::FmtFn(|f: &mut ::core::fmt::Formatter<'_>| write!(f, "{:#?}", node));
+        assert_eq!(format!("{:?}", pretty.fmt_as::<ReDebug>()), compact);
+
+        // 测试宽度、深度与隐藏字段
+        let re_debug = ReDebug::new().with_hidden(&["tag"]).with_indent(2);
+        assert_eq!(
+            format!("{:40?}", node.fmt_by(re_debug)),
+            "\
+Node {
+  id: 1,
+  children: [
+    Node { id: 2, children: [] },
+    Node { id: 3, children: [] },
+  ],
+}"
+        );
+        assert_eq!(
+            format!("{:?}", node.fmt_by(re_debug.with_max_depth(2))),
+            "Node { id: 1, children: [Node { .. }, Node { .. }] }"
+        );
+        assert_eq!(
+            format!("{:?}", node.fmt_by(ReDebug::new().with_max_depth(0))),
+            "Node { .. }"
+        );
+        let pairs = [("k", (1, 2)), ("v", (3, 4))];
+        assert_eq!(
+            format!("{:?}", pairs.fmt_by(ReDebug::new().with_width(18))),
+            "[\n    (\"k\", (1, 2)),\n    (\"v\", (3, 4)),\n]"
+        );
+    }
+}
@@ -0,0 +1,287 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DisplayProxy, FmtHandler};
+
+/// 用于折叠迭代器中连续重复元素的格式化处理器
+///
+/// 相邻且相等的元素会被合并为一项，并在其后追加标记与重复次数，如 `a, b ×3, c`.
+/// 这是 [`Repeat`](crate::Repeat) 的对偶：后者将一项输出多次，前者将多次重复的项概括为一项。
+///
+/// 这个结构体是一个适配器，它通过引用来迭代集合（不会克隆集合）
+///
+/// # 类型参数
+/// - `Delim`: 分隔符类型，必须实现 [`Display`](::core::fmt::Display) 和 [`Copy`](::core::marker::Copy) ( 可以是引用 ) 特型。
+/// - `Marker`: 重复标记类型，输出于重复次数之前，要求同 `Delim`, 默认为 `" ×"`.
+/// - `Handler`: 元素的格式化处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, FmtWithWrapper, RunLength};
+/// let vec = vec!["a", "b", "b", "b", "c"];
+/// assert_eq!(format!("{}", vec.fmt_by(RunLength::new(", "))), "a, b ×3, c");
+///
+/// let buf = [0u8; 128];
+/// let hex = |b: &&u8, f: &mut std::fmt::Formatter<'_>| write!(f, "{:#04x}", b);
+/// let proxy = buf.fmt_by(RunLength::new(", ").with_handler(FmtWithWrapper(&hex)));
+/// assert_eq!(format!("{}", proxy), "0x00 ×128");
+/// ```
+/// # 注意
+/// - 要求 &Iter 实现 [`IntoIterator`], 且其元素实现 [`PartialEq`];
+/// - 对于 &Iter 没有实现 [`IntoIterator`] 的类型（如 [`Range`](::core::ops::Range)），请使用 [`CloneIterRunLength`], 它要求 Iter 实现 [`Clone`].
+#[derive(Debug, Clone, Copy)]
+pub struct RunLength<Delim, Marker = &'static str, Handler = DisplayProxy> {
+    pub delim: Delim,
+    pub marker: Marker,
+    pub handler: Handler,
+}
+
+impl<Delim> RunLength<Delim> {
+    /// 以默认标记 `" ×"` 和 [`DisplayProxy`] 构造。
+    #[inline]
+    pub const fn new(delim: Delim) -> Self {
+        Self {
+            delim,
+            marker: " ×",
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl<Delim, Marker, Handler> RunLength<Delim, Marker, Handler> {
+    /// 替换重复标记。
+    #[inline]
+    pub fn with_marker<NewMarker>(self, marker: NewMarker) -> RunLength<Delim, NewMarker, Handler> {
+        RunLength {
+            delim: self.delim,
+            marker,
+            handler: self.handler,
+        }
+    }
+    /// 替换元素的格式化处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(
+        self,
+        handler: NewHandler,
+    ) -> RunLength<Delim, Marker, NewHandler> {
+        RunLength {
+            delim: self.delim,
+            marker: self.marker,
+            handler,
+        }
+    }
+}
+
+impl ::core::default::Default for RunLength<&'static str> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(", ")
+    }
+}
+
+impl<Iter, Delim, Marker, Handler> FmtHandler<Iter> for RunLength<Delim, Marker, Handler>
+where
+    for<'a> &'a Iter: ::core::iter::IntoIterator,
+    Iter: ?::core::marker::Sized,
+    for<'a> <&'a Iter as ::core::iter::IntoIterator>::Item: ::core::cmp::PartialEq,
+    for<'a> Handler: FmtHandler<<&'a Iter as ::core::iter::IntoIterator>::Item>,
+    Delim: ::core::fmt::Display + ::core::marker::Copy,
+    Marker: ::core::fmt::Display + ::core::marker::Copy,
+{
+    #[inline]
+    fn fmt(&self, data: &Iter, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        fmt_runs(self, data, f)
+    }
+}
+
+/// 用于折叠迭代器中连续重复元素的格式化处理器，适用于需克隆才能迭代的类型（如 [`Range`](::core::ops::Range)）
+///
+/// 除迭代方式外，与 [`RunLength`] 相同。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, CloneIterRunLength};
+/// let iter = [1, 1, 2, 3, 3].into_iter().map(|x| x * 10);
+/// assert_eq!(format!("{}", iter.fmt_by(CloneIterRunLength::new("; "))), "10 ×2; 20; 30 ×2");
+/// ```
+/// # 注意
+/// - 要求 Iter 实现 [`Clone`];
+/// - 对于 &Iter 实现了 [`IntoIterator`] 的类型，可以使用 [`RunLength`], 它不会克隆 Iter.
+#[derive(Debug, Clone, Copy)]
+pub struct CloneIterRunLength<Delim, Marker = &'static str, Handler = DisplayProxy> {
+    pub delim: Delim,
+    pub marker: Marker,
+    pub handler: Handler,
+}
+
+impl<Delim> CloneIterRunLength<Delim> {
+    /// 以默认标记 `" ×"` 和 [`DisplayProxy`] 构造。
+    #[inline]
+    pub const fn new(delim: Delim) -> Self {
+        Self {
+            delim,
+            marker: " ×",
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl<Delim, Marker, Handler> CloneIterRunLength<Delim, Marker, Handler> {
+    /// 替换重复标记。
+    #[inline]
+    pub fn with_marker<NewMarker>(
+        self,
+        marker: NewMarker,
+    ) -> CloneIterRunLength<Delim, NewMarker, Handler> {
+        CloneIterRunLength {
+            delim: self.delim,
+            marker,
+            handler: self.handler,
+        }
+    }
+    /// 替换元素的格式化处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(
+        self,
+        handler: NewHandler,
+    ) -> CloneIterRunLength<Delim, Marker, NewHandler> {
+        CloneIterRunLength {
+            delim: self.delim,
+            marker: self.marker,
+            handler,
+        }
+    }
+}
+
+impl ::core::default::Default for CloneIterRunLength<&'static str> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(", ")
+    }
+}
+
+impl<Iter, Delim, Marker, Handler> FmtHandler<Iter> for CloneIterRunLength<Delim, Marker, Handler>
+where
+    Iter: ::core::iter::IntoIterator + ::core::clone::Clone,
+    <Iter as ::core::iter::IntoIterator>::Item: ::core::cmp::PartialEq,
+    Handler: FmtHandler<<Iter as ::core::iter::IntoIterator>::Item>,
+    Delim: ::core::fmt::Display + ::core::marker::Copy,
+    Marker: ::core::fmt::Display + ::core::marker::Copy,
+{
+    #[inline]
+    fn fmt(&self, data: &Iter, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self {
+            delim,
+            marker,
+            handler,
+        } = *self;
+        fmt_runs(
+            &RunLength {
+                delim,
+                marker,
+                handler,
+            },
+            data.clone(),
+            f,
+        )
+    }
+}
+
+fn fmt_runs<I, Delim, Marker, Handler>(
+    run_length: &RunLength<Delim, Marker, Handler>,
+    iter: I,
+    f: &mut ::core::fmt::Formatter<'_>,
+) -> ::core::fmt::Result
+where
+    I: ::core::iter::IntoIterator,
+    I::Item: ::core::cmp::PartialEq,
+    Handler: FmtHandler<I::Item>,
+    Delim: ::core::fmt::Display,
+    Marker: ::core::fmt::Display,
+{
+    use ::core::fmt::Display;
+    let fmt_run = |item: &I::Item, count: usize, f: &mut ::core::fmt::Formatter<'_>| {
+        run_length.handler.fmt(item, f)?;
+        if count > 1 {
+            run_length.marker.fmt(f)?;
+            count.fmt(f)?;
+        }
+        Ok(())
+    };
+    let mut iter = iter.into_iter();
+    let Some(mut current) = iter.next() else {
+        return Ok(());
+    };
+    let mut count = 1usize;
+    for item in iter {
+        if item == current {
+            count += 1;
+            continue;
+        }
+        fmt_run(&current, count, f)?;
+        run_length.delim.fmt(f)?;
+        current = item;
+        count = 1;
+    }
+    fmt_run(&current, count, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy, LowerHexProxy};
+
+    #[test]
+    fn test_run_length() {
+        // 测试基础功能
+        let vec = vec!["a", "b", "b", "b", "c"];
+        assert_eq!(
+            format!("{}", vec.fmt_by(RunLength::new(", "))),
+            "a, b ×3, c"
+        );
+        assert_eq!(format!("{}", vec.fmt_as::<RunLength<&str>>()), "a, b ×3, c");
+
+        // 测试自定义标记
+        let proxy = vec.fmt_by(RunLength::new(" ").with_marker('*'));
+        assert_eq!(format!("{}", proxy), "a b*3 c");
+
+        // 测试空迭代器与单个元素
+        let empty: Vec<i32> = Vec::new();
+        assert_eq!(format!("{}", empty.fmt_by(RunLength::new(", "))), "");
+        assert_eq!(format!("{}", [7, 7].fmt_by(RunLength::new(", "))), "7 ×2");
+    }
+
+    #[test]
+    fn test_run_length_with_handler() {
+        // 测试与元素处理器组合
+        let buf = [0xffu8, 0, 0, 0, 0xab];
+        let proxy = buf.fmt_by(RunLength::new(" ").with_handler(LowerHexProxy));
+        assert_eq!(format!("{}", proxy), "ff 0 ×3 ab");
+
+        // 测试 CloneIterRunLength
+        let range = 1..4;
+        assert_eq!(
+            format!("{}", range.fmt_by(CloneIterRunLength::new("-"))),
+            "1-2-3"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DisplayProxy, FmtHandler, FmtTrait};
+
+/// 用于将两个处理器的多行输出并排显示的格式化处理器
+///
+/// 左右两栏分别由 `Left` 与 `Right` 格式化同一数据。左栏的每行以空格补足至其最宽一行的显示宽度
+/// （按 [`str_width`](crate::str_width) 计算），再接分隔符与右栏的对应行；行数较少的一栏以空行补齐。
+///
+/// # 类型参数
+/// - `Left`: 左栏的处理器。
+/// - `Right`: 右栏的处理器。
+/// - `Gutter`: 两栏之间的分隔符，必须实现 [`Display`](::core::fmt::Display) 和 [`Copy`](::core::marker::Copy).
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{DisplayProxy, FmtBy, ShowInvisibles, SideBySide};
+/// let text = "key = 1\nname  ";
+/// let invisibles: ShowInvisibles = ShowInvisibles::default();
+/// let layout = SideBySide(DisplayProxy, invisibles, " │ ");
+/// assert_eq!(
+///     format!("{}", text.fmt_by(layout)),
+///     "key = 1 │ key = 1␊\nname    │ name··"
+/// );
+/// ```
+/// # 注意
+/// - 输出末尾的一个换行符不产生空行。
+/// - 启用 `alloc` 特性时，两栏各格式化一次，输出暂存于堆上。未启用时不分配内存，但每输出一行，
+///   两栏都需重新格式化一次，耗时与行数的平方成正比，宜用于行数不多的输出。
+/// - 制表符按零宽处理；需按列展开时，可先使用 [`ExpandTabs`](crate::ExpandTabs).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SideBySide<Left = DisplayProxy, Right = DisplayProxy, Gutter = &'static str>(
+    pub Left,
+    pub Right,
+    pub Gutter,
+);
+
+/// 仅输出第 `target` 行（不含换行符），并累计其显示宽度。
+#[cfg(not(feature = "alloc"))]
+struct ExtractLine {
+    target: usize,
+    line: usize,
+    width: usize,
+    escapes: crate::AnsiScanner,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl crate::TextFilter for ExtractLine {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        for segment in s.split_inclusive('\n') {
+            if self.line > self.target {
+                break;
+            }
+            if self.line == self.target {
+                let content = segment.strip_suffix('\n').unwrap_or(segment);
+                out.write_str(content)?;
+                self.width += self.escapes.str_width(content);
+            }
+            self.line += segment.ends_with('\n') as usize;
+        }
+        Ok(())
+    }
+}
+
+/// 按行拆分输出，末尾的换行符不产生空行。
+#[cfg(feature = "alloc")]
+fn lines(s: &str) -> impl Iterator<Item = &str> {
+    let count = if s.is_empty() { 0 } else { usize::MAX };
+    s.strip_suffix('\n').unwrap_or(s).split('\n').take(count)
+}
+
+impl<T, Left, Right, Gutter> FmtHandler<T> for SideBySide<Left, Right, Gutter>
+where
+    T: ?::core::marker::Sized,
+    Left: FmtHandler<T>,
+    Right: FmtHandler<T>,
+    Gutter: ::core::fmt::Display + ::core::marker::Copy,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        #[cfg(feature = "alloc")]
+        {
+            let options = crate::FmtOptions::of(f);
+            let mut left = ::alloc::string::String::new();
+            options.write_by(&mut left, data, self.0, fmt_trait)?;
+            let mut right = ::alloc::string::String::new();
+            options.write_by(&mut right, data, self.1, fmt_trait)?;
+            let width = lines(&left).map(crate::str_width).max().unwrap_or(0);
+            let (mut left, mut right) = (lines(&left), lines(&right));
+            let mut first = true;
+            loop {
+                let (l, r) = match (left.next(), right.next()) {
+                    (None, None) => return Ok(()),
+                    (l, r) => (l.unwrap_or_default(), r.unwrap_or_default()),
+                };
+                if !::core::mem::take(&mut first) {
+                    f.write_char('\n')?;
+                }
+                f.write_str(l)?;
+                (crate::str_width(l)..width).try_for_each(|_| f.write_char(' '))?;
+                write!(f, "{}", self.2)?;
+                f.write_str(r)?;
+            }
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            use crate::{LineStats, fmt_filtered};
+            let mut left = LineStats::default();
+            fmt_filtered(&mut left, data, self.0, f, fmt_trait)?;
+            let mut right = LineStats::default();
+            fmt_filtered(&mut right, data, self.1, f, fmt_trait)?;
+            for target in 0..left.lines().max(right.lines()) {
+                if target != 0 {
+                    f.write_char('\n')?;
+                }
+                let mut line = ExtractLine {
+                    target,
+                    line: 0,
+                    width: 0,
+                    escapes: Default::default(),
+                };
+                fmt_filtered(&mut line, data, self.0, f, fmt_trait)?;
+                (line.width..left.widest).try_for_each(|_| f.write_char(' '))?;
+                write!(f, "{}", self.2)?;
+                let line = ExtractLine {
+                    target,
+                    line: 0,
+                    width: 0,
+                    escapes: Default::default(),
+                };
+                fmt_filtered(line, data, self.1, f, fmt_trait)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Boxed, DebugProxy, FmtAs, FmtBy, Joined, Wrap};
+
+    #[test]
+    fn test_side_by_side() {
+        // 测试行数不同的两栏
+        let text = "The quick brown fox jumps over the lazy dog.";
+        let layout = SideBySide(Wrap::new(10), Wrap::new(20), " | ");
+        assert_eq!(
+            format!("{}", text.fmt_by(layout)),
+            "\
+The quick  | The quick brown fox
+brown fox  | jumps over the lazy
+jumps over | dog.
+the lazy   | 
+dog.       | "
+        );
+        let layout = SideBySide(DebugProxy, Wrap::new(4), "  ");
+        assert_eq!(
+            format!("{}", "ab cd".fmt_by(layout)),
+            "\"ab cd\"  ab\n         cd"
+        );
+
+        // 测试空输出与末尾换行
+        assert_eq!(format!("{}", "".fmt_as::<SideBySide>()), "");
+        let layout = SideBySide(DisplayProxy, DisplayProxy, '|');
+        assert_eq!(format!("{}", "中\n".fmt_by(layout)), "中|中");
+
+        // 测试与 Boxed 组合
+        let words = ["一", "二"];
+        let layout = SideBySide(Boxed::new().with_handler(Joined("\n")), Joined(","), " ");
+        assert_eq!(
+            format!("{}", words.fmt_by(layout)),
+            "┌────┐ 一,二\n│ 一 │ \n│ 二 │ \n└────┘ "
+        );
+    }
+}
@@ -0,0 +1,302 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{FmtHandler, char_width, str_width};
+
+/// 源代码片段中的一处标注，见 [`Snippet`].
+///
+/// # 字段
+/// - `span`: 被标注内容的字节范围；为空时指向 `span.start` 处的一列。
+/// - `message`: 说明文字，显示在标注的最后一行的下划线之后，可为空。
+/// - `primary`: 是否为主要标注。主要标注以 `^` 划线，次要标注以 `-` 划线。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SnippetLabel<'a> {
+    pub span: ::core::ops::Range<usize>,
+    pub message: &'a str,
+    pub primary: bool,
+}
+
+impl<'a> SnippetLabel<'a> {
+    /// 主要标注。
+    #[inline]
+    pub const fn primary(span: ::core::ops::Range<usize>, message: &'a str) -> Self {
+        Self {
+            span,
+            message,
+            primary: true,
+        }
+    }
+    /// 次要标注。
+    #[inline]
+    pub const fn secondary(span: ::core::ops::Range<usize>, message: &'a str) -> Self {
+        Self {
+            span,
+            message,
+            primary: false,
+        }
+    }
+    /// 最后一个被标注的字节位置（空范围为其起点）。
+    #[inline]
+    fn last(&self) -> usize {
+        self.span.end.saturating_sub(1).max(self.span.start)
+    }
+    /// 将范围限制在 `source` 之内，并向外扩展至字符边界；起点大于终点时视为起点处的空范围。
+    fn clamped(&self, source: &str) -> Self {
+        let mut start = self.span.start.min(source.len());
+        while !source.is_char_boundary(start) {
+            start -= 1;
+        }
+        let mut end = self.span.end.clamp(start, source.len());
+        while !source.is_char_boundary(end) {
+            end += 1;
+        }
+        Self {
+            span: start..end,
+            ..self.clone()
+        }
+    }
+}
+
+/// 源代码中的一行。
+struct Line<'s> {
+    /// 行号，自 `1` 起。
+    number: usize,
+    /// 行首的字节位置。
+    start: usize,
+    /// 下一行行首的字节位置；最后一行为源代码长度加一，以便指向末尾的标注落在该行。
+    next: usize,
+    /// 不含换行符的内容。
+    text: &'s str,
+}
+
+impl Line<'_> {
+    #[inline]
+    fn touches(&self, label: &SnippetLabel<'_>) -> bool {
+        label.span.start < self.next && label.last() >= self.start
+    }
+}
+
+fn lines(source: &str) -> impl Iterator<Item = Line<'_>> {
+    let mut start = 0;
+    source
+        .split_inclusive('\n')
+        .enumerate()
+        .map(move |(index, segment)| {
+            let line_start = start;
+            start += segment.len();
+            let text = segment.strip_suffix('\n').unwrap_or(segment);
+            Line {
+                number: index + 1,
+                start: line_start,
+                next: if start == source.len() {
+                    start + 1
+                } else {
+                    start
+                },
+                text: text.strip_suffix('\r').unwrap_or(text),
+            }
+        })
+}
+
+/// 用于将源代码渲染为编译器风格诊断片段的格式化处理器
+///
+/// 只显示被标注的行，行前为右对齐的行号；每处标注在其所在行下方单独占一行，
+/// 以 `^`（主要）或 `-`（次要）划出被标注的内容，并在最后一行的下划线后显示说明文字。
+/// 下划线按 [`char_width`] 对齐，因而中日韩文字等宽字符可以正确标注；源代码中的制表符在对齐时原样保留。
+/// 不相邻的行之间以 `...` 表示省略。
+///
+/// # 字段
+/// - `labels`: 标注，按给出的顺序显示。
+/// - `origin`: 来源（通常为文件名），非空时在片段前显示 `--> 来源:行:列`, 行列取自第一个主要标注。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, Snippet, SnippetLabel};
+/// let source = "let 名字 = 1;\nlet y = 名字 + true;";
+/// let labels = [
+///     SnippetLabel::primary(33..37, "expected integer"),
+///     SnippetLabel::secondary(24..30, "integer"),
+/// ];
+/// assert_eq!(
+///     format!("{}", source.fmt_by(Snippet::new(&labels).with_origin("main.rs"))),
+///     " --> main.rs:2:14
+///   |
+/// 2 | let y = 名字 + true;
+///   |                ^^^^ expected integer
+///   |         ---- integer
+///   |"
+/// );
+/// ```
+/// # 注意
+/// - 要求数据实现 [`AsRef<str>`](::core::convert::AsRef); 跨越多行的标注会在每一行下划线，延续的行自第一个非空白字符起。
+/// - 标注的范围越界时被截断至源代码末尾，不在字符边界上时向外扩展至字符边界。
+#[derive(Debug, Clone, Copy)]
+pub struct Snippet<'a> {
+    pub labels: &'a [SnippetLabel<'a>],
+    pub origin: &'a str,
+}
+
+impl<'a> Snippet<'a> {
+    /// 以 `labels` 构造，不显示来源。
+    #[inline]
+    pub const fn new(labels: &'a [SnippetLabel<'a>]) -> Self {
+        Self { labels, origin: "" }
+    }
+    /// 设置来源。
+    #[inline]
+    pub const fn with_origin(self, origin: &'a str) -> Self {
+        Self { origin, ..self }
+    }
+}
+
+impl ::core::default::Default for Snippet<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl Snippet<'_> {
+    /// 输出一处标注在 `line` 上的下划线（不含行号栏）。
+    fn write_underline(
+        f: &mut ::core::fmt::Formatter<'_>,
+        line: &Line<'_>,
+        label: &SnippetLabel<'_>,
+    ) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        // 从上一行延续而来的标注，自第一个非空白字符起划线
+        let from = match label.span.start.checked_sub(line.start) {
+            Some(from) => from.min(line.text.len()),
+            None => line.text.len() - line.text.trim_start().len(),
+        };
+        let ends_here = label.last() < line.next;
+        let to = if ends_here {
+            label
+                .span
+                .end
+                .saturating_sub(line.start)
+                .min(line.text.len())
+        } else {
+            line.text.len()
+        };
+        let before = line.text.get(..from).ok_or(::core::fmt::Error)?;
+        for c in before.chars() {
+            match c {
+                '\t' => f.write_char('\t')?,
+                _ => (0..char_width(c)).try_for_each(|_| f.write_char(' '))?,
+            }
+        }
+        let marked = line
+            .text
+            .get(from..to.max(from))
+            .ok_or(::core::fmt::Error)?;
+        let mark = if label.primary { '^' } else { '-' };
+        (0..str_width(marked).max(1)).try_for_each(|_| f.write_char(mark))?;
+        if ends_here && !label.message.is_empty() {
+            write!(f, " {}", label.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> FmtHandler<T> for Snippet<'_>
+where
+    T: ?::core::marker::Sized + ::core::convert::AsRef<str>,
+{
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let source = data.as_ref();
+        let labels = || self.labels.iter().map(|label| label.clamped(source));
+        let touched = |line: &Line<'_>| labels().any(|label| line.touches(&label));
+        let last = lines(source).filter(touched).last();
+        let Some(last) = last else {
+            return Ok(());
+        };
+        let width = last.number.ilog10() as usize + 1;
+        let gutter = "";
+        if !self.origin.is_empty() {
+            write!(f, "{gutter:width$}--> {}", self.origin)?;
+            let primary = labels().find(|label| label.primary);
+            if let Some(label) = primary
+                && let Some(line) = lines(source).find(|line| line.touches(&label))
+            {
+                let column = source[line.start..label.span.start].chars().count() + 1;
+                write!(f, ":{}:{}", line.number, column)?;
+            }
+            f.write_str("\n")?;
+        }
+        write!(f, "{gutter:width$} |")?;
+        let mut previous = None;
+        for line in lines(source).filter(touched) {
+            if previous.is_some_and(|previous| previous + 1 != line.number) {
+                f.write_str("\n...")?;
+            }
+            previous = Some(line.number);
+            write!(f, "\n{:>width$} | {}", line.number, line.text)?;
+            for label in labels().filter(|label| line.touches(label)) {
+                write!(f, "\n{gutter:width$} | ")?;
+                Self::write_underline(f, &line, &label)?;
+            }
+        }
+        write!(f, "\n{gutter:width$} |")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FmtBy;
+
+    #[test]
+    fn test_snippet() {
+        // 测试跨行标注、省略与制表符
+        let source = "fn main() {\n\tlet x = (1,\n\t\t2);\n\n\n}\n";
+        let labels = [
+            SnippetLabel::primary(21..29, "tuple"),
+            SnippetLabel::secondary(33..34, ""),
+        ];
+        assert_eq!(
+            format!("{}", source.fmt_by(Snippet::new(&labels))),
+            "  |\n2 | \tlet x = (1,\n  | \t        ^^^\n3 | \t\t2);\n  | \t\t^^ tuple\n...\n6 | }\n  | -\n  |"
+        );
+
+        // 测试指向末尾的空标注与越界或不在字符边界上的范围
+        let source = "abc";
+        let labels = [SnippetLabel::primary(3..3, "expected `;`")];
+        assert_eq!(
+            format!("{}", source.fmt_by(Snippet::new(&labels).with_origin("x"))),
+            " --> x:1:4\n  |\n1 | abc\n  |    ^ expected `;`\n  |"
+        );
+        let source = "中文";
+        let labels = [
+            SnippetLabel::primary(1..2, "a"),
+            SnippetLabel::secondary(5..100, "b"),
+            SnippetLabel::secondary(::core::ops::Range { start: 9, end: 7 }, "c"),
+        ];
+        assert_eq!(
+            format!("{}", source.fmt_by(Snippet::new(&labels))),
+            "  |\n1 | 中文\n  | ^^ a\n  |   -- b\n  |     - c\n  |"
+        );
+
+        // 测试无标注
+        assert_eq!(format!("{}", "abc".fmt_by(Snippet::default())), "");
+    }
+}
@@ -0,0 +1,619 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{FmtFn, FmtHandler, WidthCounter};
+
+/// 表格的一行，由若干可显示的单元格组成，见 [`Table`].
+///
+/// 已为元素实现 [`Display`](::core::fmt::Display) 的元组（至多 12 元）、切片与数组实现。
+/// 单元格若需以特定处理器格式化，可将其包装为 [`FmtBy::fmt_by`](crate::FmtBy::fmt_by) 返回的持有者。
+pub trait TableRow {
+    /// 单元格数量。
+    fn cell_count(&self) -> usize;
+    /// 格式化第 `index` 个单元格，`index` 总小于 [`TableRow::cell_count`].
+    fn fmt_cell(&self, index: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result;
+}
+
+impl<R: TableRow + ?::core::marker::Sized> TableRow for &R {
+    #[inline]
+    fn cell_count(&self) -> usize {
+        (**self).cell_count()
+    }
+    #[inline]
+    fn fmt_cell(&self, index: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        (**self).fmt_cell(index, f)
+    }
+}
+
+impl<T: ::core::fmt::Display> TableRow for [T] {
+    #[inline]
+    fn cell_count(&self) -> usize {
+        self.len()
+    }
+    #[inline]
+    fn fmt_cell(&self, index: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self[index].fmt(f)
+    }
+}
+
+impl<T: ::core::fmt::Display, const N: usize> TableRow for [T; N] {
+    #[inline]
+    fn cell_count(&self) -> usize {
+        N
+    }
+    #[inline]
+    fn fmt_cell(&self, index: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self[index].fmt(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ::core::fmt::Display> TableRow for ::alloc::vec::Vec<T> {
+    #[inline]
+    fn cell_count(&self) -> usize {
+        self.len()
+    }
+    #[inline]
+    fn fmt_cell(&self, index: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self[index].fmt(f)
+    }
+}
+
+macro_rules! impl_table_row_for_tuple {
+    ($len: literal; $($t: ident $index: tt),+) => {
+        impl<$($t: ::core::fmt::Display),+> TableRow for ($($t,)+) {
+            #[inline]
+            fn cell_count(&self) -> usize {
+                $len
+            }
+            fn fmt_cell(
+                &self,
+                index: usize,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                match index {
+                    $($index => self.$index.fmt(f),)+
+                    _ => Ok(()),
+                }
+            }
+        }
+    };
+}
+
+impl_table_row_for_tuple!(1; A 0);
+impl_table_row_for_tuple!(2; A 0, B 1);
+impl_table_row_for_tuple!(3; A 0, B 1, C 2);
+impl_table_row_for_tuple!(4; A 0, B 1, C 2, D 3);
+impl_table_row_for_tuple!(5; A 0, B 1, C 2, D 3, E 4);
+impl_table_row_for_tuple!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_table_row_for_tuple!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_table_row_for_tuple!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_table_row_for_tuple!(9; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_table_row_for_tuple!(10; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_table_row_for_tuple!(11; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_table_row_for_tuple!(12; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+/// 表格的边框样式，见 [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TableStyle {
+    /// 以 `+`、`-`、`|` 绘制边框。
+    #[default]
+    Ascii,
+    /// 以 Unicode 制表符绘制边框。
+    Unicode,
+    /// GitHub 风格的 Markdown 表格，对齐方式体现在分隔行中。
+    Markdown,
+    /// 无边框，列间以两个空格分隔。
+    None,
+}
+
+/// 一行边框或分隔线的组成部分：左端、列间、右端与填充字符。
+#[derive(Clone, Copy)]
+struct Rule {
+    left: &'static str,
+    mid: &'static str,
+    right: &'static str,
+    fill: char,
+}
+
+impl TableStyle {
+    /// 依次为：顶边、表头下的分隔线、底边、单元格行（`fill` 不使用）。
+    fn rules(self) -> [Option<Rule>; 4] {
+        const fn rule(
+            left: &'static str,
+            mid: &'static str,
+            right: &'static str,
+            fill: char,
+        ) -> Option<Rule> {
+            Some(Rule {
+                left,
+                mid,
+                right,
+                fill,
+            })
+        }
+        match self {
+            Self::Ascii => {
+                let line = rule("+-", "-+-", "-+", '-');
+                [line, line, line, rule("| ", " | ", " |", ' ')]
+            }
+            Self::Unicode => [
+                rule("┌─", "─┬─", "─┐", '─'),
+                rule("├─", "─┼─", "─┤", '─'),
+                rule("└─", "─┴─", "─┘", '─'),
+                rule("│ ", " │ ", " │", ' '),
+            ],
+            Self::Markdown => [
+                None,
+                rule("| ", " | ", " |", '-'),
+                None,
+                rule("| ", " | ", " |", ' '),
+            ],
+            Self::None => [None, None, None, rule("", "  ", "", ' ')],
+        }
+    }
+}
+
+/// 用于将行的迭代器格式化为对齐表格的格式化处理器
+///
+/// 先遍历一次所有行以确定各列宽度，再逐行输出。宽度按 [`str_width`](crate::str_width) 计算，
+/// 因而中日韩文字等宽字符可以正确对齐；单元格不足的行以空单元格补齐。
+///
+/// # 类型参数
+/// - `Header`: 表头的类型，需实现 [`TableRow`].
+///
+/// # 字段
+/// - `header`: 表头，为 [`None`] 时不输出表头。
+/// - `align`: 各列的对齐方式，未指定的列左对齐。
+/// - `style`: 边框样式，默认为 [`TableStyle::Ascii`].
+///
+/// # 示例
+/// ``` rust
+/// use core::fmt::Alignment;
+/// use wfu::{FmtBy, Table, TableStyle};
+/// let rows = [("苹果", 3), ("banana", 12)];
+/// let table = Table::new()
+///     .with_header(["名称", "数量"])
+///     .with_align(&[Alignment::Left, Alignment::Right]);
+/// assert_eq!(
+///     format!("{}", rows.fmt_by(table)),
+///     "\
+/// +--------+------+
+/// | 名称   | 数量 |
+/// +--------+------+
+/// | 苹果   |    3 |
+/// | banana |   12 |
+/// +--------+------+"
+/// );
+/// let table = table.with_style(TableStyle::Markdown);
+/// assert_eq!(
+///     format!("{}", rows.fmt_by(table)),
+///     "\
+/// | 名称   | 数量 |
+/// | :----- | ---: |
+/// | 苹果   |    3 |
+/// | banana |   12 |"
+/// );
+/// ```
+/// # 注意
+/// - 要求 `&Iter` 实现 [`IntoIterator`], 且其元素实现 [`TableRow`]; 迭代器会被遍历两次。
+/// - 每个单元格会被格式化两次（测量与输出），右对齐与居中的单元格为三次；单元格内的换行符不做处理。
+/// - 超出 [`Table::MAX_COLUMNS`] 的列在每次输出时重新遍历所有行以测量宽度，较慢。
+/// - Markdown 样式不转义单元格中的 `|`; 未设置表头时输出空表头，各列至少宽 3 列。
+#[derive(Debug, Clone, Copy)]
+pub struct Table<'a, Header = &'static [&'static str]> {
+    pub header: Option<Header>,
+    pub align: &'a [::core::fmt::Alignment],
+    pub style: TableStyle,
+}
+
+impl Table<'_> {
+    /// 预先测量宽度的最大列数。
+    pub const MAX_COLUMNS: usize = 32;
+    /// 无表头、各列左对齐、ASCII 边框。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            header: None,
+            align: &[],
+            style: TableStyle::Ascii,
+        }
+    }
+}
+
+impl ::core::default::Default for Table<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Header> Table<'a, Header> {
+    /// 设置表头。
+    #[inline]
+    pub fn with_header<NewHeader: TableRow>(self, header: NewHeader) -> Table<'a, NewHeader> {
+        Table {
+            header: Some(header),
+            align: self.align,
+            style: self.style,
+        }
+    }
+    /// 设置各列的对齐方式。
+    #[inline]
+    pub fn with_align(self, align: &'a [::core::fmt::Alignment]) -> Self {
+        Self { align, ..self }
+    }
+    /// 设置边框样式。
+    #[inline]
+    pub fn with_style(self, style: TableStyle) -> Self {
+        Self { style, ..self }
+    }
+}
+
+/// 单元格的格式化方式，用于在输出前变换单元格的内容（如转义）。
+pub(crate) type FmtCell =
+    fn(&dyn TableRow, usize, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result;
+
+/// 原样输出单元格。
+#[inline]
+fn plain_cell(
+    row: &dyn TableRow,
+    index: usize,
+    f: &mut ::core::fmt::Formatter<'_>,
+) -> ::core::fmt::Result {
+    row.fmt_cell(index, f)
+}
+
+/// 单元格的内容，超出行的单元格数时为空。
+#[inline]
+fn cell(
+    row: &dyn TableRow,
+    index: usize,
+    fmt_cell: FmtCell,
+) -> FmtFn<impl Fn(&mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result> {
+    FmtFn(move |f: &mut ::core::fmt::Formatter<'_>| {
+        if index < row.cell_count() {
+            fmt_cell(row, index, f)
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// 转发写入的内容并累计其显示宽度。
+struct CountingWriter<'a, 'f> {
+    out: &'a mut ::core::fmt::Formatter<'f>,
+    width: usize,
+    escapes: crate::AnsiScanner,
+}
+
+impl ::core::fmt::Write for CountingWriter<'_, '_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.width += self.escapes.str_width(s);
+        self.out.write_str(s)
+    }
+}
+
+fn write_fill(f: &mut ::core::fmt::Formatter<'_>, fill: char, n: usize) -> ::core::fmt::Result {
+    use ::core::fmt::Write;
+    (0..n).try_for_each(|_| f.write_char(fill))
+}
+
+/// 单元格的显示宽度。
+fn cell_width(
+    row: &dyn TableRow,
+    index: usize,
+    fmt_cell: FmtCell,
+) -> Result<usize, ::core::fmt::Error> {
+    use ::core::fmt::Write;
+    let mut counter = WidthCounter::default();
+    write!(counter, "{}", cell(row, index, fmt_cell))?;
+    Ok(counter.0)
+}
+
+/// 记录行的单元格数，并测量前 [`Table::MAX_COLUMNS`] 个单元格的宽度。
+fn measure_row(
+    row: &dyn TableRow,
+    fmt_cell: FmtCell,
+    widths: &mut [usize; Table::MAX_COLUMNS],
+    columns: &mut usize,
+) -> ::core::fmt::Result {
+    let count = row.cell_count();
+    *columns = (*columns).max(count);
+    for (index, width) in widths.iter_mut().enumerate().take(count) {
+        *width = (*width).max(cell_width(row, index, fmt_cell)?);
+    }
+    Ok(())
+}
+
+/// 各列的宽度。前 [`Table::MAX_COLUMNS`] 列的宽度已预先测量，其余各列在需要时重新遍历所有行测量。
+struct Widths<'w> {
+    stored: &'w [usize],
+    columns: usize,
+    measure: &'w dyn Fn(usize) -> Result<usize, ::core::fmt::Error>,
+}
+
+impl Widths<'_> {
+    #[inline]
+    fn get(&self, index: usize) -> Result<usize, ::core::fmt::Error> {
+        match self.stored.get(index) {
+            Some(&width) => Ok(width),
+            None => (self.measure)(index),
+        }
+    }
+}
+
+impl<Header> Table<'_, Header> {
+    fn write_rule(
+        &self,
+        f: &mut ::core::fmt::Formatter<'_>,
+        rule: Rule,
+        widths: &Widths<'_>,
+    ) -> ::core::fmt::Result {
+        f.write_str(rule.left)?;
+        for index in 0..widths.columns {
+            let width = widths.get(index)?;
+            if index != 0 {
+                f.write_str(rule.mid)?;
+            }
+            if self.style == TableStyle::Markdown {
+                // 以冒号标记对齐方式
+                use ::core::fmt::Alignment;
+                let align = self.align.get(index).copied();
+                let (left, right) = match align {
+                    Some(Alignment::Left) => (":", ""),
+                    Some(Alignment::Right) => ("", ":"),
+                    Some(Alignment::Center) => (":", ":"),
+                    None => ("", ""),
+                };
+                f.write_str(left)?;
+                write_fill(f, rule.fill, width - left.len() - right.len())?;
+                f.write_str(right)?;
+            } else {
+                write_fill(f, rule.fill, width)?;
+            }
+        }
+        f.write_str(rule.right)
+    }
+    fn write_row(
+        &self,
+        f: &mut ::core::fmt::Formatter<'_>,
+        rule: Rule,
+        row: &dyn TableRow,
+        fmt_cell: FmtCell,
+        widths: &Widths<'_>,
+    ) -> ::core::fmt::Result {
+        use ::core::fmt::{Alignment, Write};
+        // 无边框时，行尾缺少的单元格不输出
+        let columns = if rule.right.is_empty() {
+            widths.columns.min(row.cell_count())
+        } else {
+            widths.columns
+        };
+        f.write_str(rule.left)?;
+        for index in 0..columns {
+            let width = widths.get(index)?;
+            if index != 0 {
+                f.write_str(rule.mid)?;
+            }
+            let content = cell(row, index, fmt_cell);
+            let last = index + 1 == columns;
+            let after = match self.align.get(index) {
+                Some(align @ (Alignment::Right | Alignment::Center)) => {
+                    let mut counter = WidthCounter::default();
+                    write!(counter, "{}", content)?;
+                    let padding = width.saturating_sub(counter.0);
+                    let before = if *align == Alignment::Right {
+                        padding
+                    } else {
+                        padding / 2
+                    };
+                    write_fill(f, ' ', before)?;
+                    write!(f, "{}", content)?;
+                    padding - before
+                }
+                _ => {
+                    let mut writer = CountingWriter {
+                        out: f,
+                        width: 0,
+                        escapes: Default::default(),
+                    };
+                    write!(writer, "{}", content)?;
+                    width.saturating_sub(writer.width)
+                }
+            };
+            // 无边框时，行尾不补空格
+            if !(last && rule.right.is_empty()) {
+                write_fill(f, ' ', after)?;
+            }
+        }
+        f.write_str(rule.right)
+    }
+}
+
+impl<Iter, Header> FmtHandler<Iter> for Table<'_, Header>
+where
+    for<'a> &'a Iter: ::core::iter::IntoIterator,
+    Iter: ?::core::marker::Sized,
+    for<'a> <&'a Iter as ::core::iter::IntoIterator>::Item: TableRow,
+    Header: TableRow + ::core::marker::Copy,
+{
+    #[inline]
+    fn fmt(&self, data: &Iter, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_rows(data, f, plain_cell)
+    }
+}
+
+impl<Header: TableRow> Table<'_, Header> {
+    /// 以 `fmt_cell` 格式化各单元格，输出整个表格。
+    pub(crate) fn fmt_rows<Iter>(
+        &self,
+        data: &Iter,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_cell: FmtCell,
+    ) -> ::core::fmt::Result
+    where
+        for<'a> &'a Iter: ::core::iter::IntoIterator,
+        Iter: ?::core::marker::Sized,
+        for<'a> <&'a Iter as ::core::iter::IntoIterator>::Item: TableRow,
+    {
+        let mut widths = [0; Table::MAX_COLUMNS];
+        let mut columns = 0;
+        if let Some(header) = &self.header {
+            measure_row(header, fmt_cell, &mut widths, &mut columns)?;
+        }
+        for row in data {
+            measure_row(&row, fmt_cell, &mut widths, &mut columns)?;
+        }
+        if columns == 0 {
+            return Ok(());
+        }
+        // Markdown 表格各列至少宽 3 列
+        let min_width = if self.style == TableStyle::Markdown {
+            3
+        } else {
+            0
+        };
+        let stored = &mut widths[..columns.min(Table::MAX_COLUMNS)];
+        stored
+            .iter_mut()
+            .for_each(|width| *width = (*width).max(min_width));
+        let measure = |index: usize| {
+            let mut width = min_width;
+            if let Some(header) = &self.header {
+                width = width.max(cell_width(header, index, fmt_cell)?);
+            }
+            for row in data {
+                width = width.max(cell_width(&row, index, fmt_cell)?);
+            }
+            Ok(width)
+        };
+        let widths = &Widths {
+            stored,
+            columns,
+            measure: &measure,
+        };
+        let [top, separator, bottom, cells] = self.style.rules();
+        let cells = cells.ok_or(::core::fmt::Error)?;
+        // 除第一行外，每行之前换行
+        let mut first = true;
+        let mut new_line = |f: &mut ::core::fmt::Formatter<'_>| {
+            if ::core::mem::take(&mut first) {
+                Ok(())
+            } else {
+                f.write_str("\n")
+            }
+        };
+        if let Some(top) = top {
+            new_line(f)?;
+            self.write_rule(f, top, widths)?;
+        }
+        let header = match &self.header {
+            Some(header) => Some(header as &dyn TableRow),
+            // Markdown 表格必须有表头
+            None if self.style == TableStyle::Markdown => Some(&[""; 0] as &dyn TableRow),
+            None => None,
+        };
+        if let Some(header) = header {
+            new_line(f)?;
+            self.write_row(f, cells, header, fmt_cell, widths)?;
+            if let Some(separator) = separator {
+                new_line(f)?;
+                self.write_rule(f, separator, widths)?;
+            }
+        }
+        for row in data {
+            new_line(f)?;
+            self.write_row(f, cells, &row, fmt_cell, widths)?;
+        }
+        if let Some(bottom) = bottom {
+            new_line(f)?;
+            self.write_rule(f, bottom, widths)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtBy, Repeat};
+    use ::core::fmt::Alignment;
+
+    #[test]
+    fn test_table_styles() {
+        let rows = [["a", "bb"], ["ccc", "d"]];
+        let table = Table::new().with_header(("x", "y"));
+        assert_eq!(
+            format!("{}", rows.fmt_by(table.with_style(TableStyle::Unicode))),
+            "┌─────┬────┐\n│ x   │ y  │\n├─────┼────┤\n│ a   │ bb │\n│ ccc │ d  │\n└─────┴────┘"
+        );
+        assert_eq!(
+            format!("{}", rows.fmt_by(table.with_style(TableStyle::None))),
+            "x    y\na    bb\nccc  d"
+        );
+        // 测试无表头的 ASCII 与 Markdown 表格
+        assert_eq!(
+            format!("{}", rows.fmt_by(Table::new())),
+            "+-----+----+\n| a   | bb |\n| ccc | d  |\n+-----+----+"
+        );
+        let markdown = Table::new()
+            .with_style(TableStyle::Markdown)
+            .with_align(&[Alignment::Center]);
+        assert_eq!(
+            format!("{}", rows.fmt_by(markdown)),
+            "|     |     |\n| :-: | --- |\n|  a  | bb  |\n| ccc | d   |"
+        );
+    }
+
+    #[test]
+    fn test_table_rows() {
+        // 测试不等长的行与单元格中的持有者
+        let stars = "*";
+        let rows: [&[&dyn ::core::fmt::Display]; 2] = [&[&1, &stars.fmt_by(Repeat(3))], &[&"中文"]];
+        let table = Table::new()
+            .with_header(["n"])
+            .with_align(&[Alignment::Right, Alignment::Center])
+            .with_style(TableStyle::None);
+        assert_eq!(format!("{}", rows.fmt_by(table)), "   n\n   1  ***\n中文");
+
+        // 测试空表格
+        let empty: [(i32,); 0] = [];
+        assert_eq!(format!("{}", empty.fmt_by(Table::new())), "");
+        let table = Table::new().with_header(("only",));
+        assert_eq!(
+            format!("{}", empty.fmt_by(table)),
+            "+------+\n| only |\n+------+\n+------+"
+        );
+
+        // 测试列数超出预先测量的上限
+        let mut wide = [[0; Table::MAX_COLUMNS + 2]; 2];
+        wide[1][Table::MAX_COLUMNS] = 10;
+        let table = Table::new().with_style(TableStyle::None);
+        let zeros = "0  ".repeat(Table::MAX_COLUMNS);
+        assert_eq!(
+            format!("{}", wide.fmt_by(table)),
+            format!("{}0   0\n{}10  0", zeros, zeros)
+        );
+    }
+}
@@ -0,0 +1,498 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DisplayProxy, FmtFn, FmtHandler, FmtTrait, TextFilter, fmt_filtered};
+
+/// 树的节点，见 [`Tree`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::TreeNode;
+/// struct Dir {
+///     name: &'static str,
+///     entries: Vec<Dir>,
+/// }
+/// impl TreeNode for Dir {
+///     type Label = str;
+///     fn label(&self) -> &str {
+///         self.name
+///     }
+///     fn children(&self) -> impl Iterator<Item = &Self> {
+///         self.entries.iter()
+///     }
+/// }
+/// ```
+pub trait TreeNode {
+    /// 标签的类型，由 [`Tree`] 的内部处理器格式化。
+    type Label: ?::core::marker::Sized;
+    fn label(&self) -> &Self::Label;
+    fn children(&self) -> impl Iterator<Item = &Self>;
+}
+
+/// 树形引导线的样式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TreeStyle {
+    /// `├── `、`└── ` 与 `│   `.
+    #[default]
+    Unicode,
+    /// `|-- `、`` `-- `` 与 `|   `.
+    Ascii,
+}
+
+impl TreeStyle {
+    /// 依次为：中间的分支、最后的分支、延续的竖线、空白。
+    const fn guides(self) -> [&'static str; 4] {
+        match self {
+            Self::Unicode => ["├── ", "└── ", "│   ", "    "],
+            Self::Ascii => ["|-- ", "`-- ", "|   ", "    "],
+        }
+    }
+    const fn ellipsis(self) -> &'static str {
+        match self {
+            Self::Unicode => "…",
+            Self::Ascii => "...",
+        }
+    }
+}
+
+/// 树的显示选项，[`Tree`] 与 [`TreeBy`] 共用。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TreeOptions {
+    pub style: TreeStyle,
+    /// 展开的最大深度（根节点深度为 `0`），更深的子节点被省略。
+    pub max_depth: Option<usize>,
+    /// 每个节点显示的最多子节点数，其余被省略。
+    pub max_children: Option<usize>,
+}
+
+/// 祖先各层是否为其父节点的最后一个子节点，以栈上的链表存放。
+struct Guides<'p> {
+    parent: Option<&'p Guides<'p>>,
+    last: bool,
+}
+
+/// 在标签的每个后续行前写入引导线，使多行标签不打断树形。
+struct LabelFilter<'g> {
+    options: TreeOptions,
+    guides: Option<&'g Guides<'g>>,
+    at_line_start: bool,
+}
+
+impl TextFilter for LabelFilter<'_> {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        for line in s.split_inclusive('\n') {
+            if ::core::mem::take(&mut self.at_line_start) {
+                self.options.write_prefix(out, self.guides)?;
+            }
+            out.write_str(line)?;
+            self.at_line_start = line.ends_with('\n');
+        }
+        Ok(())
+    }
+}
+
+/// 访问节点的标签与子节点。
+trait TreeAccess<N: ?::core::marker::Sized> {
+    fn fmt_label(&self, node: &N, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result;
+    fn children<'n>(&self, node: &'n N) -> impl Iterator<Item = &'n N>;
+}
+
+impl TreeOptions {
+    fn write_prefix(
+        self,
+        f: &mut ::core::fmt::Formatter<'_>,
+        guides: Option<&Guides<'_>>,
+    ) -> ::core::fmt::Result {
+        let Some(guides) = guides else {
+            return Ok(());
+        };
+        self.write_prefix(f, guides.parent)?;
+        let [_, _, vertical, blank] = self.style.guides();
+        f.write_str(if guides.last { blank } else { vertical })
+    }
+    /// 另起一行，写入引导线与分支。
+    fn write_branch(
+        self,
+        f: &mut ::core::fmt::Formatter<'_>,
+        guides: Option<&Guides<'_>>,
+        last: bool,
+    ) -> ::core::fmt::Result {
+        f.write_str("\n")?;
+        self.write_prefix(f, guides)?;
+        let [branch, last_branch, _, _] = self.style.guides();
+        f.write_str(if last { last_branch } else { branch })
+    }
+    fn write_node<N, A>(
+        self,
+        f: &mut ::core::fmt::Formatter<'_>,
+        access: &A,
+        node: &N,
+        guides: Option<&Guides<'_>>,
+        depth: usize,
+    ) -> ::core::fmt::Result
+    where
+        N: ?::core::marker::Sized,
+        A: TreeAccess<N>,
+    {
+        let mut children = access.children(node).peekable();
+        let filter = LabelFilter {
+            options: self,
+            guides,
+            at_line_start: false,
+        };
+        let label = FmtFn(|f: &mut ::core::fmt::Formatter<'_>| access.fmt_label(node, f));
+        fmt_filtered(filter, &label, DisplayProxy, f, FmtTrait::Display)?;
+        if children.peek().is_none() {
+            return Ok(());
+        }
+        let ellipsis = self.style.ellipsis();
+        if self.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+            self.write_branch(f, guides, true)?;
+            return write!(f, "{} {} more", ellipsis, children.count());
+        }
+        let mut shown = 0;
+        while let Some(child) = children.next() {
+            if self.max_children == Some(shown) {
+                self.write_branch(f, guides, true)?;
+                return write!(f, "{} {} more", ellipsis, children.count() + 1);
+            }
+            let last = children.peek().is_none();
+            self.write_branch(f, guides, last)?;
+            let child_guides = Guides {
+                parent: guides,
+                last,
+            };
+            self.write_node(f, access, child, Some(&child_guides), depth + 1)?;
+            shown += 1;
+        }
+        Ok(())
+    }
+}
+
+/// 用于将实现了 [`TreeNode`] 的数据格式化为树形图的格式化处理器
+///
+/// 根节点的标签独占第一行，其后每个子节点占一行，以引导线表示层级。节点的标签经 `handler` 格式化；
+/// 超出深度或数量限制的子节点以 `… N more` 表示。
+///
+/// # 字段
+/// - `options`: 显示选项，见 [`TreeOptions`].
+/// - `handler`: 标签的格式化处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, Tree, TreeNode};
+/// struct Dep(&'static str, &'static [Dep]);
+/// impl TreeNode for Dep {
+///     type Label = str;
+///     fn label(&self) -> &str {
+///         self.0
+///     }
+///     fn children(&self) -> impl Iterator<Item = &Self> {
+///         self.1.iter()
+///     }
+/// }
+/// let root = Dep("app", &[Dep("serde", &[Dep("serde_derive", &[])]), Dep("log", &[])]);
+/// assert_eq!(
+///     format!("{}", root.fmt_by(Tree::new())),
+///     "\
+/// app
+/// ├── serde
+/// │   └── serde_derive
+/// └── log"
+/// );
+/// ```
+/// # 注意
+/// - 以递归实现，不分配内存；极深的树可能耗尽栈空间，可设置 [`TreeOptions::max_depth`].
+/// - 多行标签的后续行会接上引导线，并与第一行的标签对齐。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tree<Handler = DisplayProxy> {
+    pub options: TreeOptions,
+    pub handler: Handler,
+}
+
+impl Tree {
+    /// 以默认选项与 [`DisplayProxy`] 构造。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            options: TreeOptions {
+                style: TreeStyle::Unicode,
+                max_depth: None,
+                max_children: None,
+            },
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl<Handler> Tree<Handler> {
+    /// 替换引导线样式。
+    #[inline]
+    pub fn with_style(mut self, style: TreeStyle) -> Self {
+        self.options.style = style;
+        self
+    }
+    /// 设置展开的最大深度。
+    #[inline]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.options.max_depth = Some(max_depth);
+        self
+    }
+    /// 设置每个节点显示的最多子节点数。
+    #[inline]
+    pub fn with_max_children(mut self, max_children: usize) -> Self {
+        self.options.max_children = Some(max_children);
+        self
+    }
+    /// 替换标签的格式化处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> Tree<NewHandler> {
+        Tree {
+            options: self.options,
+            handler,
+        }
+    }
+}
+
+impl<N, Handler> TreeAccess<N> for Tree<Handler>
+where
+    N: TreeNode + ?::core::marker::Sized,
+    Handler: FmtHandler<N::Label>,
+{
+    #[inline]
+    fn fmt_label(&self, node: &N, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.handler.fmt(node.label(), f)
+    }
+    #[inline]
+    fn children<'n>(&self, node: &'n N) -> impl Iterator<Item = &'n N> {
+        node.children()
+    }
+}
+
+impl<N, Handler> FmtHandler<N> for Tree<Handler>
+where
+    N: TreeNode + ?::core::marker::Sized,
+    Handler: FmtHandler<N::Label>,
+{
+    #[inline]
+    fn fmt(&self, data: &N, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.options.write_node(f, self, data, None, 0)
+    }
+}
+
+/// 用于以闭包描述树的结构并将其格式化为树形图的格式化处理器
+///
+/// 与 [`Tree`] 相同，但无需实现 [`TreeNode`]: `label` 负责格式化节点的标签，`children` 返回节点的子节点。
+///
+/// # 字段
+/// - `options`: 显示选项，见 [`TreeOptions`].
+/// - `label`: 形如 `Fn(&T, &mut Formatter) -> fmt::Result` 的闭包。
+/// - `children`: 形如 `Fn(&T) -> &[T]` 的闭包。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, TreeBy, TreeStyle};
+/// enum Expr {
+///     Num(i32),
+///     Add(Vec<Expr>),
+/// }
+/// let ast = Expr::Add(vec![Expr::Num(1), Expr::Add(vec![Expr::Num(2), Expr::Num(3)])]);
+/// let tree = TreeBy::new(
+///     |e: &Expr, f: &mut std::fmt::Formatter| match e {
+///         Expr::Num(n) => write!(f, "Num({})", n),
+///         Expr::Add(_) => f.write_str("Add"),
+///     },
+///     |e: &Expr| match e {
+///         Expr::Num(_) => &[],
+///         Expr::Add(args) => args.as_slice(),
+///     },
+/// )
+/// .with_style(TreeStyle::Ascii);
+/// assert_eq!(
+///     format!("{}", ast.fmt_by(tree)),
+///     "Add\n|-- Num(1)\n`-- Add\n    |-- Num(2)\n    `-- Num(3)"
+/// );
+/// ```
+/// # 注意
+/// - 两个闭包都须实现 [`Copy`], 即不可捕获可变引用或非 `Copy` 的值。
+#[derive(Debug, Clone, Copy)]
+pub struct TreeBy<LabelFn, ChildrenFn> {
+    pub options: TreeOptions,
+    pub label: LabelFn,
+    pub children: ChildrenFn,
+}
+
+impl<LabelFn, ChildrenFn> TreeBy<LabelFn, ChildrenFn> {
+    /// 以默认选项构造。
+    #[inline]
+    pub fn new<T>(label: LabelFn, children: ChildrenFn) -> Self
+    where
+        LabelFn: Fn(&T, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+        ChildrenFn: Fn(&T) -> &[T],
+    {
+        Self {
+            options: TreeOptions::default(),
+            label,
+            children,
+        }
+    }
+    /// 替换引导线样式。
+    #[inline]
+    pub fn with_style(mut self, style: TreeStyle) -> Self {
+        self.options.style = style;
+        self
+    }
+    /// 设置展开的最大深度。
+    #[inline]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.options.max_depth = Some(max_depth);
+        self
+    }
+    /// 设置每个节点显示的最多子节点数。
+    #[inline]
+    pub fn with_max_children(mut self, max_children: usize) -> Self {
+        self.options.max_children = Some(max_children);
+        self
+    }
+}
+
+impl<T, LabelFn, ChildrenFn> TreeAccess<T> for TreeBy<LabelFn, ChildrenFn>
+where
+    LabelFn: Fn(&T, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+    ChildrenFn: Fn(&T) -> &[T],
+{
+    #[inline]
+    fn fmt_label(&self, node: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        (self.label)(node, f)
+    }
+    #[inline]
+    fn children<'n>(&self, node: &'n T) -> impl Iterator<Item = &'n T> {
+        (self.children)(node).iter()
+    }
+}
+
+impl<T, LabelFn, ChildrenFn> FmtHandler<T> for TreeBy<LabelFn, ChildrenFn>
+where
+    LabelFn: Fn(&T, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result + ::core::marker::Copy,
+    ChildrenFn: Fn(&T) -> &[T] + ::core::marker::Copy,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.options.write_node(f, self, data, None, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DebugProxy, FmtBy};
+
+    struct Node(u32, Vec<Node>);
+
+    impl TreeNode for Node {
+        type Label = u32;
+        fn label(&self) -> &u32 {
+            &self.0
+        }
+        fn children(&self) -> impl Iterator<Item = &Self> {
+            self.1.iter()
+        }
+    }
+
+    fn sample() -> Node {
+        Node(
+            0,
+            vec![
+                Node(1, vec![Node(11, vec![Node(111, vec![])]), Node(12, vec![])]),
+                Node(2, vec![]),
+                Node(3, vec![]),
+                Node(4, vec![]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_tree() {
+        let root = sample();
+        assert_eq!(
+            format!("{}", root.fmt_by(Tree::new())),
+            "0\n├── 1\n│   ├── 11\n│   │   └── 111\n│   └── 12\n├── 2\n├── 3\n└── 4"
+        );
+
+        // 测试省略
+        let tree = Tree::new().with_max_depth(1).with_max_children(2);
+        assert_eq!(
+            format!("{}", root.fmt_by(tree)),
+            "0\n├── 1\n│   └── … 2 more\n├── 2\n└── … 2 more"
+        );
+        let tree = Tree::new()
+            .with_style(TreeStyle::Ascii)
+            .with_max_depth(0)
+            .with_handler(DebugProxy);
+        assert_eq!(format!("{}", root.fmt_by(tree)), "0\n`-- ... 4 more");
+
+        // 测试单个节点
+        assert_eq!(format!("{}", Node(7, vec![]).fmt_by(Tree::new())), "7");
+
+        // 测试多行标签：后续行接上引导线
+        struct Text(&'static str, Vec<Text>);
+        impl TreeNode for Text {
+            type Label = str;
+            fn label(&self) -> &str {
+                self.0
+            }
+            fn children(&self) -> impl Iterator<Item = &Self> {
+                self.1.iter()
+            }
+        }
+        let root = Text(
+            "r\ns",
+            vec![
+                Text("a\nb", vec![Text("c\nd", vec![])]),
+                Text("e\nf", vec![]),
+            ],
+        );
+        assert_eq!(
+            format!("{}", root.fmt_by(Tree::new())),
+            "r\ns\n├── a\n│   b\n│   └── c\n│       d\n└── e\n    f"
+        );
+    }
+
+    #[test]
+    fn test_tree_by() {
+        // 以闭包访问子节点，并以闭包格式化标签
+        struct Item(&'static str, Vec<Item>);
+        let root = Item(
+            "root",
+            vec![Item("a", vec![]), Item("b", vec![Item("c", vec![])])],
+        );
+        let tree = TreeBy::new(
+            |node: &Item, f: &mut ::core::fmt::Formatter| write!(f, "<{}>", node.0),
+            |node: &Item| node.1.as_slice(),
+        )
+        .with_max_children(1);
+        assert_eq!(
+            format!("{}", root.fmt_by(tree)),
+            "<root>\n├── <a>\n└── … 1 more"
+        );
+    }
+}
@@ -0,0 +1,534 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    DisplayProxy, FmtHandler, FmtTrait, PendingRuns, TextFilter, char_width, impl_filter_handler,
+};
+
+fn write_run(out: &mut ::core::fmt::Formatter<'_>, c: char, count: usize) -> ::core::fmt::Result {
+    use ::core::fmt::Write;
+    (0..count).try_for_each(|_| out.write_char(c))
+}
+
+struct TrimFilter {
+    started: bool,
+    pending: PendingRuns<16>,
+}
+
+impl TextFilter for TrimFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        for c in s.chars() {
+            if c.is_whitespace() {
+                if self.started {
+                    self.pending.push(c, |c, count| write_run(out, c, count))?;
+                }
+                continue;
+            }
+            self.pending.drain(|c, count| write_run(out, c, count))?;
+            out.write_char(c)?;
+            self.started = true;
+        }
+        Ok(())
+    }
+    #[inline]
+    fn finish(&mut self, _: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        // 丢弃末尾的空白。
+        self.pending.drain(|_, _| Ok(()))
+    }
+}
+
+/// 去除内部处理器输出首尾空白的格式化处理器
+///
+/// 流式处理，仅暂存尚未确定是否位于末尾的空白，不分配内存。
+///
+/// # 类型参数
+/// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, Trim};
+/// assert_eq!(format!("[{}]", "\n  a b \t\n".fmt_as::<Trim>()), "[a b]");
+/// ```
+/// # 注意
+/// - 空白按游程暂存，不同空白字符交替超过 16 段时，较早的部分将被提前输出。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Trim<Handler = DisplayProxy>(pub Handler);
+
+impl_filter_handler!(
+    Trim,
+    |this| TrimFilter {
+        started: false,
+        pending: PendingRuns::new(),
+    },
+    this.0
+);
+
+/// 去除内部处理器输出末尾空白的格式化处理器
+///
+/// 流式处理，仅暂存尚未确定是否位于末尾的空白，不分配内存。
+///
+/// # 类型参数
+/// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, TrimEnd};
+/// assert_eq!(format!("[{}]", "  a b \t\n".fmt_as::<TrimEnd>()), "[  a b]");
+/// ```
+/// # 注意
+/// - 空白按游程暂存，不同空白字符交替超过 16 段时，较早的部分将被提前输出。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrimEnd<Handler = DisplayProxy>(pub Handler);
+
+impl_filter_handler!(
+    TrimEnd,
+    |this| TrimFilter {
+        started: true,
+        pending: PendingRuns::new(),
+    },
+    this.0
+);
+
+struct SqueezeFilter {
+    in_whitespace: bool,
+}
+
+impl TextFilter for SqueezeFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        for c in s.chars() {
+            let whitespace = c.is_whitespace();
+            if !whitespace {
+                out.write_char(c)?;
+            } else if !self.in_whitespace {
+                out.write_char(' ')?;
+            }
+            self.in_whitespace = whitespace;
+        }
+        Ok(())
+    }
+}
+
+/// 将内部处理器输出中的每段连续空白（包括换行）折叠为一个空格的格式化处理器
+///
+/// # 类型参数
+/// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtAs, Squeeze};
+/// assert_eq!(format!("{}", "a  b\n\t c ".fmt_as::<Squeeze>()), "a b c ");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Squeeze<Handler = DisplayProxy>(pub Handler);
+
+impl_filter_handler!(
+    Squeeze,
+    |this| SqueezeFilter {
+        in_whitespace: false,
+    },
+    this.0
+);
+
+struct ExpandTabsFilter {
+    width: usize,
+    column: usize,
+    escapes: crate::AnsiScanner,
+}
+
+impl TextFilter for ExpandTabsFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        for c in s.chars() {
+            match c {
+                _ if self.escapes.in_escape(c) => {}
+                '\t' => {
+                    let spaces = self.width - self.column % self.width;
+                    (0..spaces).try_for_each(|_| out.write_char(' '))?;
+                    self.column += spaces;
+                    continue;
+                }
+                '\n' | '\r' => self.column = 0,
+                _ => self.column += char_width(c),
+            }
+            out.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// 将内部处理器输出中的制表符按列展开为空格的格式化处理器
+///
+/// 每个制表符展开至下一个制表位，制表位间隔为 `width` 列，列号在每行开头归零；列宽按 [`str_width`](crate::str_width) 计算，
+/// 即忽略 ANSI 转义序列。
+///
+/// # 字段
+/// - `width`: 制表位间隔，为 `0` 时制表符被删除。
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{ExpandTabs, FmtBy};
+/// assert_eq!(format!("{}", "a\tbc\td\n\te".fmt_by(ExpandTabs::new(4))), "a   bc  d\n    e");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ExpandTabs<Handler = DisplayProxy> {
+    pub width: usize,
+    pub handler: Handler,
+}
+
+impl ExpandTabs {
+    /// 以 [`DisplayProxy`] 为内部处理器构造。
+    #[inline]
+    pub const fn new(width: usize) -> Self {
+        Self {
+            width,
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl<Handler: ::core::default::Default> ::core::default::Default for ExpandTabs<Handler> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            width: 8,
+            handler: Handler::default(),
+        }
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for ExpandTabs<Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        crate::pad_output(f, |f, options| {
+            if self.width == 0 {
+                let remove_tabs = TabRemover;
+                return crate::fmt_filtered_with(
+                    remove_tabs,
+                    data,
+                    self.handler,
+                    f,
+                    options,
+                    fmt_trait,
+                );
+            }
+            let filter = ExpandTabsFilter {
+                width: self.width,
+                column: 0,
+                escapes: crate::AnsiScanner::default(),
+            };
+            crate::fmt_filtered_with(filter, data, self.handler, f, options, fmt_trait)
+        })
+    }
+}
+
+struct TabRemover;
+
+impl TextFilter for TabRemover {
+    #[inline]
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        s.split('\t').try_for_each(|part| out.write_str(part))
+    }
+}
+
+/// 扫描各非空白行的缩进，求其最小值。
+struct IndentScan {
+    in_indent: bool,
+    current: usize,
+    min: Option<usize>,
+}
+
+impl TextFilter for IndentScan {
+    fn write_str(&mut self, _: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        for c in s.chars() {
+            match c {
+                '\n' => {
+                    self.in_indent = true;
+                    self.current = 0;
+                }
+                ' ' | '\t' if self.in_indent => self.current += 1,
+                _ if c.is_whitespace() => {}
+                _ if self.in_indent => {
+                    self.in_indent = false;
+                    self.min = Some(self.min.map_or(self.current, |min| min.min(self.current)));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+struct DedentFilter {
+    indent: usize,
+    skipped: usize,
+    in_indent: bool,
+}
+
+impl TextFilter for DedentFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        for c in s.chars() {
+            match c {
+                '\n' => {
+                    self.in_indent = true;
+                    self.skipped = 0;
+                }
+                ' ' | '\t' if self.in_indent && self.skipped < self.indent => {
+                    self.skipped += 1;
+                    continue;
+                }
+                _ => self.in_indent = false,
+            }
+            out.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// 去除内部处理器输出中各行公共缩进的格式化处理器
+///
+/// 分两遍处理：第一遍求出所有非空白行缩进（行首的空格与制表符）的最小值，第二遍从每行开头去除这么多个缩进字符。
+/// 内部处理器会被调用两次，不分配内存；指定宽度且未启用 `alloc` 特性时为四次。
+///
+/// # 类型参数
+/// - `Handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{Dedent, FmtAs};
+/// let s = "    fn main() {\n        body();\n\n    }";
+/// assert_eq!(format!("{}", s.fmt_as::<Dedent>()), "fn main() {\n    body();\n\n}");
+/// ```
+/// # 注意
+/// - 空格与制表符各计为一个缩进字符，不检查各行缩进是否由相同字符组成。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dedent<Handler = DisplayProxy>(pub Handler);
+
+impl<T, Handler> FmtHandler<T> for Dedent<Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        crate::pad_output(f, |f, options| {
+            let mut scan = IndentScan {
+                in_indent: true,
+                current: 0,
+                min: None,
+            };
+            crate::fmt_filtered_with(&mut scan, data, self.0, f, options, fmt_trait)?;
+            let filter = DedentFilter {
+                indent: scan.min.unwrap_or(0),
+                skipped: 0,
+                in_indent: true,
+            };
+            crate::fmt_filtered_with(filter, data, self.0, f, options, fmt_trait)
+        })
+    }
+}
+
+/// 换行符风格
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl LineEnding {
+    #[inline]
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+struct LineEndingsFilter {
+    ending: LineEnding,
+    pending_cr: bool,
+}
+
+impl TextFilter for LineEndingsFilter {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        let ending = self.ending.as_str();
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            // 输出 `\r` 之前的片段；`\r` 本身暂不输出，以便与其后（可能位于下一段）的 `\n` 合并。
+            let flush_cr = ::core::mem::replace(&mut self.pending_cr, c == '\r');
+            if c == '\r' || c == '\n' {
+                out.write_str(&s[start..i])?;
+                start = i + 1;
+                if flush_cr && c == '\r' || c == '\n' {
+                    out.write_str(ending)?;
+                }
+            } else if flush_cr {
+                out.write_str(ending)?;
+            }
+        }
+        out.write_str(&s[start..])
+    }
+    #[inline]
+    fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        if self.pending_cr {
+            out.write_str(self.ending.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+/// 统一内部处理器输出中换行符的格式化处理器
+///
+/// `\r\n`、`\n` 及单独的 `\r` 均视为一个换行，并统一输出为 `ending` 所指定的换行符。
+///
+/// # 字段
+/// - `ending`: 目标换行符风格，默认为 [`LineEnding::Lf`].
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{DisplayProxy, FmtAs, FmtBy, LineEnding, LineEndings};
+/// let s = "a\r\nb\nc\rd";
+/// assert_eq!(format!("{}", s.fmt_as::<LineEndings>()), "a\nb\nc\nd");
+/// let crlf = LineEndings { ending: LineEnding::CrLf, handler: DisplayProxy };
+/// assert_eq!(format!("{}", s.fmt_by(crlf)), "a\r\nb\r\nc\r\nd");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LineEndings<Handler = DisplayProxy> {
+    pub ending: LineEnding,
+    pub handler: Handler,
+}
+
+impl_filter_handler!(
+    LineEndings,
+    |this| LineEndingsFilter {
+        ending: this.ending,
+        pending_cr: false,
+    },
+    this.handler
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtAs, FmtBy, FmtFn, Joined};
+
+    #[test]
+    fn test_trim_and_squeeze() {
+        // 测试跨多次写入的空白
+        let items = [" ", "a", " \n", "\t", "b", "  "];
+        assert_eq!(format!("[{}]", items.fmt_by(Trim(Joined("")))), "[a \n\tb]");
+        assert_eq!(
+            format!("[{}]", items.fmt_by(TrimEnd(Joined("")))),
+            "[ a \n\tb]"
+        );
+        assert_eq!(
+            format!("[{}]", items.fmt_by(Squeeze(Joined("")))),
+            "[ a b ]"
+        );
+        assert_eq!(format!("[{}]", " \t\n".fmt_as::<Trim>()), "[]");
+
+        // 测试宽度作用于过滤后的输出
+        assert_eq!(format!("[{:>8}]", "  ab  ".fmt_as::<Trim>()), "[      ab]");
+        assert_eq!(
+            format!("[{:-<6}]", " ab \n".fmt_as::<TrimEnd>()),
+            "[ ab---]"
+        );
+        assert_eq!(format!("[{:>6}]", "a \t b".fmt_as::<Squeeze>()), "[   a b]");
+    }
+
+    #[test]
+    fn test_expand_tabs_and_dedent() {
+        assert_eq!(format!("{}", "\t|".fmt_as::<ExpandTabs>()), "        |");
+        assert_eq!(format!("{}", "a\tb".fmt_by(ExpandTabs::new(0))), "ab");
+        // 测试宽字符占两列
+        assert_eq!(format!("{}", "中\tx".fmt_by(ExpandTabs::new(4))), "中  x");
+        // 测试转义序列不占宽度
+        assert_eq!(
+            format!("{}", "\x1b[1mab\x1b[0m\tx".fmt_by(ExpandTabs::new(4))),
+            "\x1b[1mab\x1b[0m  x"
+        );
+
+        let s = "\n\t  x\n\t\ty\n   \n";
+        assert_eq!(format!("{}", s.fmt_as::<Dedent>()), "\n x\ny\n \n");
+        assert_eq!(format!("{}", "".fmt_as::<Dedent>()), "");
+
+        // 测试宽度作用于过滤后的输出
+        assert_eq!(
+            format!("[{:>8}]", "a\tb".fmt_by(ExpandTabs::new(2))),
+            "[     a b]"
+        );
+        assert_eq!(
+            format!("[{:>8}]", "a\tb".fmt_as::<ExpandTabs>()),
+            "[a       b]"
+        );
+        assert_eq!(format!("[{:*^5}]", "  a".fmt_as::<Dedent>()), "[**a**]");
+    }
+
+    #[test]
+    fn test_line_endings() {
+        // 测试跨多次写入的 `\r\n`
+        let fmt_fn = |f: &mut ::core::fmt::Formatter<'_>| {
+            f.write_str("a\r")?;
+            f.write_str("\nb\r")?;
+            f.write_str("\r")
+        };
+        let crlf = LineEndings {
+            ending: LineEnding::CrLf,
+            handler: crate::FmtFnProxy,
+        };
+        assert_eq!(format!("{}", fmt_fn.fmt_by(crlf)), "a\r\nb\r\n\r\n");
+        assert_eq!(
+            format!("{}", FmtFn(fmt_fn).fmt_as::<LineEndings>()),
+            "a\nb\n\n"
+        );
+        // 测试宽度作用于过滤后的输出
+        assert_eq!(format!("[{:>3}]", "a\r".fmt_as::<LineEndings>()), "[  a\n]");
+    }
+}
@@ -0,0 +1,331 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// 东亚宽字符（UAX #11 中的 W 与 F 类）的码位范围，按起点升序排列。
+const WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115f),
+    (0x231a, 0x231b),
+    (0x2329, 0x232a),
+    (0x23e9, 0x23ec),
+    (0x23f0, 0x23f0),
+    (0x23f3, 0x23f3),
+    (0x25fd, 0x25fe),
+    (0x2614, 0x2615),
+    (0x2648, 0x2653),
+    (0x267f, 0x267f),
+    (0x2693, 0x2693),
+    (0x26a1, 0x26a1),
+    (0x26aa, 0x26ab),
+    (0x26bd, 0x26be),
+    (0x26c4, 0x26c5),
+    (0x26ce, 0x26ce),
+    (0x26d4, 0x26d4),
+    (0x26ea, 0x26ea),
+    (0x26f2, 0x26f3),
+    (0x26f5, 0x26f5),
+    (0x26fa, 0x26fa),
+    (0x26fd, 0x26fd),
+    (0x2705, 0x2705),
+    (0x270a, 0x270b),
+    (0x2728, 0x2728),
+    (0x274c, 0x274c),
+    (0x274e, 0x274e),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27b0, 0x27b0),
+    (0x27bf, 0x27bf),
+    (0x2b1b, 0x2b1c),
+    (0x2b50, 0x2b50),
+    (0x2b55, 0x2b55),
+    (0x2e80, 0x303e),
+    (0x3041, 0x33ff),
+    (0x3400, 0x4dbf),
+    (0x4e00, 0x9fff),
+    (0xa000, 0xa4cf),
+    (0xa960, 0xa97f),
+    (0xac00, 0xd7a3),
+    (0xf900, 0xfaff),
+    (0xfe10, 0xfe19),
+    (0xfe30, 0xfe6f),
+    (0xff00, 0xff60),
+    (0xffe0, 0xffe6),
+    (0x16fe0, 0x16fe4),
+    (0x17000, 0x18cff),
+    (0x1b000, 0x1b2ff),
+    (0x1f004, 0x1f004),
+    (0x1f0cf, 0x1f0cf),
+    (0x1f18e, 0x1f18e),
+    (0x1f191, 0x1f19a),
+    (0x1f200, 0x1f202),
+    (0x1f210, 0x1f23b),
+    (0x1f240, 0x1f248),
+    (0x1f250, 0x1f251),
+    (0x1f260, 0x1f265),
+    (0x1f300, 0x1f320),
+    (0x1f32d, 0x1f335),
+    (0x1f337, 0x1f37c),
+    (0x1f37e, 0x1f393),
+    (0x1f3a0, 0x1f3ca),
+    (0x1f3cf, 0x1f3d3),
+    (0x1f3e0, 0x1f3f0),
+    (0x1f3f4, 0x1f3f4),
+    (0x1f3f8, 0x1f43e),
+    (0x1f440, 0x1f440),
+    (0x1f442, 0x1f4fc),
+    (0x1f4ff, 0x1f53d),
+    (0x1f54b, 0x1f54e),
+    (0x1f550, 0x1f567),
+    (0x1f57a, 0x1f57a),
+    (0x1f595, 0x1f596),
+    (0x1f5a4, 0x1f5a4),
+    (0x1f5fb, 0x1f64f),
+    (0x1f680, 0x1f6c5),
+    (0x1f6cc, 0x1f6cc),
+    (0x1f6d0, 0x1f6d2),
+    (0x1f6d5, 0x1f6d7),
+    (0x1f6dc, 0x1f6df),
+    (0x1f6eb, 0x1f6ec),
+    (0x1f6f4, 0x1f6fc),
+    (0x1f7e0, 0x1f7eb),
+    (0x1f7f0, 0x1f7f0),
+    (0x1f90c, 0x1f93a),
+    (0x1f93c, 0x1f945),
+    (0x1f947, 0x1f9ff),
+    (0x1fa70, 0x1faff),
+    (0x20000, 0x2fffd),
+    (0x30000, 0x3fffd),
+];
+
+/// 零宽字符（组合附加符号、变体选择符、韩文字母的中声与终声等）的码位范围，按起点升序排列。
+const ZERO: &[(u32, u32)] = &[
+    (0x0300, 0x036f),
+    (0x0483, 0x0489),
+    (0x0591, 0x05bd),
+    (0x0610, 0x061a),
+    (0x064b, 0x065f),
+    (0x0e31, 0x0e31),
+    (0x0e34, 0x0e3a),
+    (0x0e47, 0x0e4e),
+    (0x1160, 0x11ff),
+    (0x1ab0, 0x1aff),
+    (0x1dc0, 0x1dff),
+    (0x20d0, 0x20ff),
+    (0x302a, 0x302d),
+    (0x3099, 0x309a),
+    (0xd7b0, 0xd7ff),
+    (0xfe00, 0xfe0f),
+    (0xfe20, 0xfe2f),
+    (0x1f3fb, 0x1f3ff),
+    (0xe0000, 0xe0fff),
+];
+
+#[inline]
+fn in_table(table: &[(u32, u32)], c: u32) -> bool {
+    table
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                ::core::cmp::Ordering::Less
+            } else if start > c {
+                ::core::cmp::Ordering::Greater
+            } else {
+                ::core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// 字符在等宽终端中的显示宽度
+///
+/// - 控制字符、组合附加符号、零宽及双向控制等格式字符为 `0`;
+/// - 东亚宽字符（中日韩表意文字、假名、谚文音节、全角形式及大部分 emoji）为 `2`;
+/// - 其余字符为 `1`.
+///
+/// # 示例
+/// ``` rust
+/// use wfu::char_width;
+/// assert_eq!(char_width('a'), 1);
+/// assert_eq!(char_width('中'), 2);
+/// assert_eq!(char_width('\u{301}'), 0);
+/// ```
+/// # 注意
+/// - 依据 UAX #11 的常见范围近似实现，不依赖外部数据表；歧义宽度（A 类）字符按窄字符处理。
+#[inline]
+pub fn char_width(c: char) -> usize {
+    let code = c as u32;
+    if code < 0x7f {
+        return (code >= 0x20) as usize;
+    }
+    if c.is_control() || crate::is_invisible_format(c) || in_table(ZERO, code) {
+        0
+    } else if in_table(WIDE, code) {
+        2
+    } else {
+        1
+    }
+}
+
+/// 字符串在等宽终端中的显示宽度，即各字符 [`char_width`] 之和。ANSI 转义序列（如颜色）不占宽度。
+///
+/// # 示例
+/// ``` rust
+/// use wfu::str_width;
+/// assert_eq!(str_width("中文abc"), 7);
+/// assert_eq!(str_width("\x1b[31mred\x1b[0m"), 3);
+/// ```
+#[inline]
+pub fn str_width(s: &str) -> usize {
+    AnsiScanner::default().str_width(s)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum AnsiState {
+    #[default]
+    Text,
+    /// 刚读到 ESC.
+    Escape,
+    /// 控制序列（CSI），直至 `@` 到 `~` 之间的结束字符。
+    Csi,
+    /// 操作系统命令（OSC），直至 BEL 或 `ESC \`.
+    Osc,
+    /// OSC 中刚读到 ESC.
+    OscEscape,
+}
+
+/// 逐字符识别 ANSI 转义序列，序列可跨越多次写入。
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct AnsiScanner(AnsiState);
+
+impl AnsiScanner {
+    /// `c` 是否属于转义序列。
+    pub(crate) fn in_escape(&mut self, c: char) -> bool {
+        self.0 = match (self.0, c) {
+            (AnsiState::Text, '\x1b') => AnsiState::Escape,
+            (AnsiState::Text, _) => return false,
+            (AnsiState::Escape, '[') => AnsiState::Csi,
+            (AnsiState::Escape, ']') => AnsiState::Osc,
+            (AnsiState::Csi, '\x40'..='\x7e') => AnsiState::Text,
+            (AnsiState::Osc, '\x07') => AnsiState::Text,
+            (AnsiState::Osc, '\x1b') => AnsiState::OscEscape,
+            (AnsiState::OscEscape, '\\') => AnsiState::Text,
+            (AnsiState::OscEscape, _) => AnsiState::Osc,
+            (AnsiState::Escape, _) => AnsiState::Text,
+            (state, _) => state,
+        };
+        true
+    }
+    /// `c` 的显示宽度，属于转义序列时为 `0`.
+    #[inline]
+    pub(crate) fn width(&mut self, c: char) -> usize {
+        match self.in_escape(c) {
+            true => 0,
+            false => char_width(c),
+        }
+    }
+    /// `s` 的显示宽度，转义序列可始于此前写入的内容。
+    #[inline]
+    pub(crate) fn str_width(&mut self, s: &str) -> usize {
+        s.chars().map(|c| self.width(c)).sum()
+    }
+}
+
+/// 仅累计写入文本显示宽度的 [`Write`](::core::fmt::Write) 实现，用于在输出前测量宽度。
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct WidthCounter(pub(crate) usize, AnsiScanner);
+
+impl ::core::fmt::Write for WidthCounter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.0 += self.1.str_width(s);
+        Ok(())
+    }
+}
+
+/// 统计最宽一行的显示宽度与行数，不产生输出。末尾的换行符不产生空行。
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct LineStats {
+    column: usize,
+    /// 当前行是否已有内容。
+    open: bool,
+    lines: usize,
+    pub(crate) widest: usize,
+    escapes: AnsiScanner,
+}
+
+impl LineStats {
+    /// 行数，空输出为 `0`.
+    #[inline]
+    pub(crate) fn lines(&self) -> usize {
+        self.lines + self.open as usize
+    }
+}
+
+impl crate::TextFilter for LineStats {
+    fn write_str(&mut self, _: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        for c in s.chars() {
+            if c == '\n' {
+                self.lines += 1;
+                self.column = 0;
+                self.open = false;
+            } else if !self.escapes.in_escape(c) {
+                self.column += char_width(c);
+                self.widest = self.widest.max(self.column);
+                self.open = true;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 是否为东亚宽字符，此类字符之间允许断行。
+#[inline]
+pub(crate) fn is_wide(c: char) -> bool {
+    (c as u32) >= 0x1100 && in_table(WIDE, c as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width() {
+        assert_eq!(str_width(""), 0);
+        assert_eq!(str_width("hello"), 5);
+        assert_eq!(str_width("你好，世界！"), 12);
+        assert_eq!(str_width("ｶﾞ"), 2);
+        assert_eq!(str_width("한국어"), 6);
+        assert_eq!(str_width("e\u{301}"), 1);
+        assert_eq!(str_width("\t\n\u{200b}"), 0);
+        assert_eq!(str_width("🦀"), 2);
+        // ANSI 转义序列不占宽度
+        assert_eq!(str_width("\x1b[1;38;5;208m中\x1b[0m"), 2);
+        assert_eq!(str_width("\x1b]8;;http://a\x1b\\a\x1b]8;;\x07"), 1);
+        let mut counter = WidthCounter::default();
+        ::core::fmt::Write::write_str(&mut counter, "\x1b[3").unwrap();
+        ::core::fmt::Write::write_str(&mut counter, "1mab").unwrap();
+        assert_eq!(counter.0, 2);
+        // 表内各区间须升序且互不重叠，以便二分查找
+        for table in [WIDE, ZERO] {
+            assert!(table.windows(2).all(|w| w[0].1 < w[1].0));
+        }
+    }
+}
@@ -0,0 +1,441 @@
+// MIT License
+//
+// Copyright (c) 2026 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    AnsiScanner, DisplayProxy, FmtHandler, FmtTrait, TextFilter, char_width, is_wide, str_width,
+};
+
+/// 不可位于行首的标点（避头点），它们总是附着在前一个字符之后。
+#[inline]
+fn is_closing_punct(c: char) -> bool {
+    matches!(
+        c,
+        '，' | '。'
+            | '、'
+            | '：'
+            | '；'
+            | '！'
+            | '？'
+            | '）'
+            | '」'
+            | '』'
+            | '】'
+            | '〉'
+            | '》'
+            | '〕'
+            | '］'
+            | '｝'
+            | '…'
+            | '—'
+            | 'ー'
+            | '々'
+            | 'ぁ'
+            | 'ぃ'
+            | 'ぅ'
+            | 'ぇ'
+            | 'ぉ'
+            | 'っ'
+            | 'ゃ'
+            | 'ゅ'
+            | 'ょ'
+            | 'ァ'
+            | 'ィ'
+            | 'ゥ'
+            | 'ェ'
+            | 'ォ'
+            | 'ッ'
+            | 'ャ'
+            | 'ュ'
+            | 'ョ'
+    )
+}
+
+/// 暂存单词的缓冲区大小（字节）。
+const WORD_CAPACITY: usize = 256;
+
+/// 按显示宽度断行的状态机，供 [`Wrap`] 等处理器使用。
+pub(crate) struct WrapFilter<'a> {
+    width: usize,
+    indent: &'a str,
+    subsequent_indent: &'a str,
+    break_words: bool,
+    /// 当前行所用缩进的显示宽度。
+    line_start: usize,
+    /// 当前列，包括尚未写出的缩进。
+    column: usize,
+    indent_written: bool,
+    first_line: bool,
+    /// 当前行是否已有单词。
+    has_content: bool,
+    /// 尚未写出的空白数量。
+    pending_spaces: usize,
+    word: [u8; WORD_CAPACITY],
+    word_len: usize,
+    word_width: usize,
+    /// 当前单词以宽字符结尾，其后允许断行。
+    word_breakable: bool,
+    /// 当前单词超出缓冲区，其余部分直接写出。
+    streaming: bool,
+    /// 识别输入中的转义序列，转义序列不占宽度，也不作为断行处。
+    escapes: AnsiScanner,
+    /// 识别已写出单词中的转义序列。
+    written_escapes: AnsiScanner,
+}
+
+impl<'a> WrapFilter<'a> {
+    pub(crate) fn new(
+        width: usize,
+        indent: &'a str,
+        subsequent_indent: &'a str,
+        break_words: bool,
+    ) -> Self {
+        let line_start = str_width(indent);
+        Self {
+            width,
+            indent,
+            subsequent_indent,
+            break_words,
+            line_start,
+            column: line_start,
+            indent_written: false,
+            first_line: true,
+            has_content: false,
+            pending_spaces: 0,
+            word: [0; WORD_CAPACITY],
+            word_len: 0,
+            word_width: 0,
+            word_breakable: false,
+            streaming: false,
+            escapes: AnsiScanner::default(),
+            written_escapes: AnsiScanner::default(),
+        }
+    }
+    fn write_indent(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        if !self.indent_written {
+            self.indent_written = true;
+            out.write_str(if self.first_line {
+                self.indent
+            } else {
+                self.subsequent_indent
+            })?;
+        }
+        Ok(())
+    }
+    fn new_line(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        out.write_str("\n")?;
+        self.first_line = false;
+        self.indent_written = false;
+        self.line_start = str_width(self.subsequent_indent);
+        self.column = self.line_start;
+        self.has_content = false;
+        self.pending_spaces = 0;
+        Ok(())
+    }
+    /// 写出单词的一部分，必要时在字符之间断行。
+    fn write_word_part(
+        &mut self,
+        out: &mut ::core::fmt::Formatter<'_>,
+        s: &str,
+    ) -> ::core::fmt::Result {
+        use ::core::fmt::Write;
+        for c in s.chars() {
+            let width = self.written_escapes.width(c);
+            if self.break_words
+                && width != 0
+                && self.column > self.line_start
+                && self.column + width > self.width
+            {
+                self.new_line(out)?;
+            }
+            self.write_indent(out)?;
+            out.write_char(c)?;
+            self.column += width;
+        }
+        self.has_content = true;
+        Ok(())
+    }
+    /// 决定当前单词放在本行还是下一行，写出其前的空白与已暂存的部分。
+    fn place_word(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        if self.has_content && self.column + self.pending_spaces + self.word_width > self.width {
+            self.new_line(out)?;
+        }
+        self.write_indent(out)?;
+        for _ in 0..::core::mem::take(&mut self.pending_spaces) {
+            out.write_str(" ")?;
+            self.column += 1;
+        }
+        let len = ::core::mem::take(&mut self.word_len);
+        self.word_width = 0;
+        let word = self.word;
+        let word = ::core::str::from_utf8(&word[..len]).map_err(|_| ::core::fmt::Error)?;
+        self.write_word_part(out, word)
+    }
+    fn end_word(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.word_breakable = false;
+        if ::core::mem::take(&mut self.streaming) {
+            return Ok(());
+        }
+        if self.word_len != 0 {
+            self.place_word(out)?;
+        }
+        Ok(())
+    }
+    /// 将显示宽度为 `width` 的字符追加到当前单词。
+    fn push_word_char(
+        &mut self,
+        out: &mut ::core::fmt::Formatter<'_>,
+        c: char,
+        width: usize,
+    ) -> ::core::fmt::Result {
+        if self.streaming {
+            let mut buf = [0; 4];
+            return self.write_word_part(out, c.encode_utf8(&mut buf));
+        }
+        if self.word_len + c.len_utf8() > WORD_CAPACITY {
+            // 单词过长，按已知的部分决定位置，其余部分直接写出。
+            self.place_word(out)?;
+            self.streaming = true;
+            return self.push_word_char(out, c, width);
+        }
+        c.encode_utf8(&mut self.word[self.word_len..]);
+        self.word_len += c.len_utf8();
+        self.word_width += width;
+        Ok(())
+    }
+}
+
+impl TextFilter for WrapFilter<'_> {
+    fn write_str(&mut self, out: &mut ::core::fmt::Formatter<'_>, s: &str) -> ::core::fmt::Result {
+        for c in s.chars() {
+            if self.escapes.in_escape(c) {
+                // 转义序列附着于当前单词（或下一个单词之前）
+                self.push_word_char(out, c, 0)?;
+                continue;
+            }
+            match c {
+                '\n' => {
+                    self.end_word(out)?;
+                    self.new_line(out)?;
+                }
+                _ if c.is_whitespace() => {
+                    self.end_word(out)?;
+                    self.pending_spaces += 1;
+                }
+                _ => {
+                    let wide = is_wide(c) && !is_closing_punct(c);
+                    if wide || self.word_breakable && !is_closing_punct(c) {
+                        self.end_word(out)?;
+                    }
+                    self.push_word_char(out, c, char_width(c))?;
+                    self.word_breakable = wide || self.word_breakable && is_closing_punct(c);
+                }
+            }
+        }
+        Ok(())
+    }
+    #[inline]
+    fn finish(&mut self, out: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.end_word(out)
+    }
+}
+
+/// 将内部处理器的输出按显示宽度重新断行的格式化处理器
+///
+/// 以贪心方式将单词填入宽度为 `width` 列的行中；中日韩表意文字等宽字符之间亦可断行，
+/// 但避头标点（如 `，`、`。`）不会出现在行首。原有的换行符被保留，单词间的空白在断行处被丢弃。
+/// 显示宽度按 [`str_width`] 计算，ANSI 转义序列不占宽度，也不会在其中断行。
+///
+/// # 字段
+/// - `width`: 目标行宽（包括缩进）。
+/// - `indent`: 第一行的缩进。
+/// - `subsequent_indent`: 其余各行的缩进。
+/// - `break_words`: 是否在单个单词宽于一行时于字符之间断开，否则任其超出。
+/// - `handler`: 内部处理器，默认为 [`DisplayProxy`].
+///
+/// # 示例
+/// ``` rust
+/// use wfu::{FmtBy, Wrap};
+/// let text = "The quick brown fox jumps over the lazy dog.";
+/// assert_eq!(
+///     format!("{}", text.fmt_by(Wrap::new(16))),
+///     "The quick brown\nfox jumps over\nthe lazy dog."
+/// );
+/// let text = "汉字之间可以断行，标点不会出现在行首。";
+/// assert_eq!(
+///     format!("{}", text.fmt_by(Wrap::new(16))),
+///     "汉字之间可以断\n行，标点不会出现\n在行首。"
+/// );
+/// ```
+/// # 注意
+/// - 单词在不超过 256 字节时暂存于栈上，不分配内存；更长的单词按已暂存的部分决定是否换行，其余部分直接写出。
+/// - 制表符视作一个空白；需按列展开时，可先使用 [`ExpandTabs`](crate::ExpandTabs).
+#[derive(Debug, Clone, Copy)]
+pub struct Wrap<'a, Handler = DisplayProxy> {
+    pub width: usize,
+    pub indent: &'a str,
+    pub subsequent_indent: &'a str,
+    pub break_words: bool,
+    pub handler: Handler,
+}
+
+impl Wrap<'_> {
+    /// 无缩进、允许断开单词，以 [`DisplayProxy`] 为内部处理器构造。
+    #[inline]
+    pub const fn new(width: usize) -> Self {
+        Self {
+            width,
+            indent: "",
+            subsequent_indent: "",
+            break_words: true,
+            handler: DisplayProxy,
+        }
+    }
+}
+
+impl<'a, Handler> Wrap<'a, Handler> {
+    /// 替换缩进。
+    #[inline]
+    pub fn with_indent(self, indent: &'a str, subsequent_indent: &'a str) -> Self {
+        Self {
+            indent,
+            subsequent_indent,
+            ..self
+        }
+    }
+    /// 替换内部处理器。
+    #[inline]
+    pub fn with_handler<NewHandler>(self, handler: NewHandler) -> Wrap<'a, NewHandler> {
+        Wrap {
+            width: self.width,
+            indent: self.indent,
+            subsequent_indent: self.subsequent_indent,
+            break_words: self.break_words,
+            handler,
+        }
+    }
+}
+
+impl<T, Handler> FmtHandler<T> for Wrap<'_, Handler>
+where
+    T: ?::core::marker::Sized,
+    Handler: FmtHandler<T>,
+{
+    #[inline]
+    fn fmt(&self, data: &T, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        self.fmt_trait(data, f, FmtTrait::Display)
+    }
+    fn fmt_trait(
+        &self,
+        data: &T,
+        f: &mut ::core::fmt::Formatter<'_>,
+        fmt_trait: FmtTrait,
+    ) -> ::core::fmt::Result {
+        let filter = WrapFilter::new(
+            self.width,
+            self.indent,
+            self.subsequent_indent,
+            self.break_words,
+        );
+        crate::fmt_filtered(filter, data, self.handler, f, fmt_trait)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FmtBy, Joined};
+
+    #[test]
+    fn test_wrap() {
+        // 测试缩进与保留原有换行
+        let text = "usage: tool [options]\n\nOptions are parsed left to right.";
+        let wrap = Wrap::new(14).with_indent("", "  ");
+        assert_eq!(
+            format!("{}", text.fmt_by(wrap)),
+            "usage: tool\n  [options]\n\n  Options are\n  parsed left\n  to right."
+        );
+
+        // 测试过长的单词
+        assert_eq!(
+            format!("{}", "a abcdefghij".fmt_by(Wrap::new(4))),
+            "a\nabcd\nefgh\nij"
+        );
+        let no_break = Wrap {
+            break_words: false,
+            ..Wrap::new(4)
+        };
+        assert_eq!(
+            format!("{}", "a abcdefghij b".fmt_by(no_break)),
+            "a\nabcdefghij\nb"
+        );
+
+        // 测试与 Joined 组合
+        let words = ["alpha", "beta", "gamma", "delta"];
+        let proxy = words.fmt_by(Wrap::new(11).with_handler(Joined(" ")));
+        assert_eq!(format!("{}", proxy), "alpha beta\ngamma delta");
+    }
+
+    #[test]
+    fn test_wrap_cjk() {
+        // 宽字符之间可以断行，与拉丁字母混排
+        assert_eq!(
+            format!("{}", "中文和English混排".fmt_by(Wrap::new(8))),
+            "中文和\nEnglish\n混排"
+        );
+        // 避头标点附着在前一个字符之后
+        assert_eq!(
+            format!("{}", "你好，世界。".fmt_by(Wrap::new(4))),
+            "你\n好，\n世\n界。"
+        );
+
+        // 超出缓冲区的单词
+        let long = "x".repeat(300);
+        let text = format!("a {}", long);
+        let output = format!("{}", text.fmt_by(Wrap::new(100)));
+        assert!(output.lines().all(|line| line.len() <= 100));
+        assert_eq!(output.replace('\n', ""), format!("a{}", long));
+    }
+
+    #[test]
+    fn test_wrap_ansi() {
+        // 转义序列不占宽度，也不会在其中断行
+        let red = crate::Styled::new().with_fg(crate::Color::Red);
+        let words = ["aa", "bb", "cc"].map(|word| word.fmt_by(red));
+        let styled = |text: &str| format!("\x1b[31m{}\x1b[0m", text);
+        let proxy = words.fmt_by(Wrap::new(8).with_handler(Joined(" ")));
+        assert_eq!(
+            format!("{}", proxy),
+            [styled("aa"), styled("bb"), styled("cc")].join(" ")
+        );
+        let proxy = words.fmt_by(Wrap::new(5).with_handler(Joined(" ")));
+        assert_eq!(
+            format!("{}", proxy),
+            format!("{} {}\n{}", styled("aa"), styled("bb"), styled("cc"))
+        );
+        // 宽字符之间的转义序列附着在前一个单词之后
+        let text = format!("{}{}", styled("中文"), styled("字"));
+        assert_eq!(
+            format!("{}", text.fmt_by(Wrap::new(4))),
+            "\x1b[31m中文\x1b[0m\x1b[31m\n字\x1b[0m"
+        );
+    }
+}